@@ -1,4 +1,4 @@
-use truthy::{Truthy, truthy};
+use truthy::truthy;
 
 fn main() {
     let x = 1; // truthy
@@ -13,4 +13,5 @@ fn main() {
     println!("truthy!((x && !y) || z) => {:?}", truthy!((x && !y) || z));
     println!("truthy!(!(x && y) || z) => {:?}", truthy!(!(x && y) || z));
     println!("truthy!(((!(!x) && !!!y) || z)) => {:?}", truthy!(((!(!x) && !!!y) || z)));
+    println!("truthy!(x + y && z.len() < 2) => {:?}", truthy!(x + y && z.len() < 2));
 }
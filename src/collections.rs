@@ -0,0 +1,129 @@
+//! Implementations of `Truthy` for the collections in `std`
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+use crate::policy::Policy;
+use crate::Truthy;
+
+macro_rules! impl_truthy_collection {
+    ($hook:ident: $type:ident<$($param:ident),+>) => {
+        impl<$($param),+> Truthy for $type<$($param),+> {
+            /// `true` if not empty
+            fn truthy(&self) -> bool {
+                !self.is_empty()
+            }
+            fn truthy_under(&self, policy: &dyn Policy) -> bool {
+                policy.$hook(self.len())
+            }
+        }
+    };
+}
+
+impl_truthy_collection!(seq: Vec<T>);
+impl_truthy_collection!(seq: VecDeque<T>);
+impl_truthy_collection!(seq: LinkedList<T>);
+impl_truthy_collection!(seq: BinaryHeap<T>);
+impl_truthy_collection!(seq: HashSet<T, S>);
+impl_truthy_collection!(seq: BTreeSet<T>);
+impl_truthy_collection!(map: HashMap<K, V, S>);
+impl_truthy_collection!(map: BTreeMap<K, V>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{JavaScript, JsonLogic};
+
+    fn is_truthy<T: Truthy>(value: T) -> bool {
+        value.truthy()
+    }
+
+    mod vecs {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(vec![0u8]));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(Vec::<u8>::new()));
+        }
+    }
+    mod vec_deques {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(VecDeque::from(vec![()])));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(VecDeque::<u8>::new()));
+        }
+    }
+    mod linked_lists {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(std::iter::once("").collect::<LinkedList<_>>()));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(LinkedList::<u8>::new()));
+        }
+    }
+    mod binary_heaps {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(BinaryHeap::from(vec![0u8])));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(BinaryHeap::<u8>::new()));
+        }
+    }
+    mod sets {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(std::iter::once(0u8).collect::<HashSet<_>>()));
+            assert!(is_truthy(std::iter::once(0u8).collect::<BTreeSet<_>>()));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(HashSet::<u8>::new()));
+            assert!(!is_truthy(BTreeSet::<u8>::new()));
+        }
+    }
+    mod maps {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(std::iter::once(("a", 0u8)).collect::<HashMap<_, _>>()));
+            assert!(is_truthy(std::iter::once(("a", 0u8)).collect::<BTreeMap<_, _>>()));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(HashMap::<u8, u8>::new()));
+            assert!(!is_truthy(BTreeMap::<u8, u8>::new()));
+        }
+    }
+
+    #[test]
+    fn policy() {
+        assert!(Vec::<u8>::new().truthy_with::<JavaScript>());
+        assert!(BTreeSet::<u8>::new().falsy_with::<JsonLogic>());
+        assert!(HashMap::<u8, u8>::new().truthy_with::<JsonLogic>());
+        assert!(BTreeMap::<u8, u8>::new().truthy_with::<JavaScript>());
+    }
+}
@@ -0,0 +1,205 @@
+//! Read a `bool` from an environment variable
+//!
+//! `std::env::var("X").map(|v| v.truthy())` treats `X=0` and `X=false` as `true`, because the
+//! strings aren't empty. These functions use [`parse_bool`] instead.
+//!
+//! ```
+//! use truthy::env;
+//!
+//! std::env::set_var("MY_APP_DEBUG", "off");
+//! assert!(!env::var_is_truthy("MY_APP_DEBUG"));
+//! assert_eq!(env::var_bool("MY_APP_DEBUG"), Ok(Some(false)));
+//! assert_eq!(env::var_bool("MY_APP_UNSET"), Ok(None));
+//! assert_eq!(env::var_bool_or("MY_APP_UNSET", true), Ok(true));
+//! ```
+use std::error::Error;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+
+use super::parse::{parse_bool, ParseTruthyError};
+
+/// `true` if the variable is set to a truthy word, like `"1"` or `"yes"`.
+///
+/// Unset, empty, and invalid values are all `false`.
+pub fn var_is_truthy<K: AsRef<OsStr>>(name: K) -> bool {
+    matches!(var_bool(name), Ok(Some(true)))
+}
+
+/// Parses the variable with [`parse_bool`].
+///
+/// Returns `Ok(None)` if the variable isn't set.
+pub fn var_bool<K: AsRef<OsStr>>(name: K) -> Result<Option<bool>, EnvBoolError> {
+    let name = name.as_ref();
+    let error = |kind| EnvBoolError { name: name.to_owned(), kind };
+    let value = match std::env::var_os(name) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    let value = value
+        .into_string()
+        .map_err(|value| error(EnvBoolErrorKind::NotUnicode(value)))?;
+    if value.is_empty() {
+        return Err(error(EnvBoolErrorKind::Empty));
+    }
+    parse_bool(&value)
+        .map(Some)
+        .map_err(|e| error(EnvBoolErrorKind::Invalid(e)))
+}
+
+/// Parses the variable with [`parse_bool`], using `default` if it's unset or empty.
+pub fn var_bool_or<K: AsRef<OsStr>>(name: K, default: bool) -> Result<bool, EnvBoolError> {
+    match var_bool(name) {
+        Ok(value) => Ok(value.unwrap_or(default)),
+        Err(e) if matches!(e.kind, EnvBoolErrorKind::Empty) => Ok(default),
+        Err(e) => Err(e),
+    }
+}
+
+/// The error returned when an environment variable can't be read as a `bool`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvBoolError {
+    name: OsString,
+    kind: EnvBoolErrorKind,
+}
+
+impl EnvBoolError {
+    /// The name of the variable
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// Why the variable couldn't be read
+    pub fn kind(&self) -> &EnvBoolErrorKind {
+        &self.kind
+    }
+}
+
+/// The reason for an [`EnvBoolError`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnvBoolErrorKind {
+    /// The variable is set to an empty string
+    Empty,
+    /// The variable isn't valid unicode
+    NotUnicode(OsString),
+    /// The variable isn't a recognized word
+    Invalid(ParseTruthyError),
+}
+
+impl fmt::Display for EnvBoolError {
+    /// Writes the variable's name and the reason, like `` `DEBUG`: invalid boolean 'maybe' ``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: ", self.name.to_string_lossy())?;
+        match &self.kind {
+            EnvBoolErrorKind::Empty => write!(f, "environment variable is empty"),
+            EnvBoolErrorKind::NotUnicode(value) => write!(f, "not valid unicode: {:?}", value),
+            EnvBoolErrorKind::Invalid(e) => write!(f, "invalid boolean '{}'", e.input()),
+        }
+    }
+}
+
+impl Error for EnvBoolError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            EnvBoolErrorKind::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    mod var_is_truthy {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            env::set_var("TRUTHY_TEST_IS_TRUTHY_YES", "yes");
+            assert!(var_is_truthy("TRUTHY_TEST_IS_TRUTHY_YES"));
+        }
+
+        #[test]
+        fn falsy() {
+            env::set_var("TRUTHY_TEST_IS_TRUTHY_ZERO", "0");
+            env::set_var("TRUTHY_TEST_IS_TRUTHY_FALSE", "false");
+            env::set_var("TRUTHY_TEST_IS_TRUTHY_EMPTY", "");
+            env::set_var("TRUTHY_TEST_IS_TRUTHY_INVALID", "maybe");
+            assert!(!var_is_truthy("TRUTHY_TEST_IS_TRUTHY_ZERO"));
+            assert!(!var_is_truthy("TRUTHY_TEST_IS_TRUTHY_FALSE"));
+            assert!(!var_is_truthy("TRUTHY_TEST_IS_TRUTHY_EMPTY"));
+            assert!(!var_is_truthy("TRUTHY_TEST_IS_TRUTHY_INVALID"));
+            assert!(!var_is_truthy("TRUTHY_TEST_IS_TRUTHY_UNSET"));
+        }
+    }
+    mod var_bool {
+        use super::*;
+
+        #[test]
+        fn set() {
+            env::set_var("TRUTHY_TEST_VAR_BOOL_ON", "On");
+            env::set_var("TRUTHY_TEST_VAR_BOOL_OFF", "off");
+            assert_eq!(var_bool("TRUTHY_TEST_VAR_BOOL_ON"), Ok(Some(true)));
+            assert_eq!(var_bool("TRUTHY_TEST_VAR_BOOL_OFF"), Ok(Some(false)));
+        }
+
+        #[test]
+        fn unset() {
+            assert_eq!(var_bool("TRUTHY_TEST_VAR_BOOL_UNSET"), Ok(None));
+        }
+
+        #[test]
+        fn empty() {
+            env::set_var("TRUTHY_TEST_VAR_BOOL_EMPTY", "");
+            let err = var_bool("TRUTHY_TEST_VAR_BOOL_EMPTY").unwrap_err();
+            assert_eq!(err.name(), "TRUTHY_TEST_VAR_BOOL_EMPTY");
+            assert_eq!(err.kind(), &EnvBoolErrorKind::Empty);
+        }
+
+        #[test]
+        fn invalid() {
+            env::set_var("TRUTHY_TEST_VAR_BOOL_INVALID", "maybe");
+            let err = var_bool("TRUTHY_TEST_VAR_BOOL_INVALID").unwrap_err();
+            match err.kind() {
+                EnvBoolErrorKind::Invalid(e) => assert_eq!(e.input(), "maybe"),
+                other => panic!("expected invalid error, got {:?}", other),
+            }
+            let message = "`TRUTHY_TEST_VAR_BOOL_INVALID`: invalid boolean 'maybe'";
+            assert_eq!(err.to_string(), message);
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn not_unicode() {
+            use std::os::unix::ffi::OsStrExt;
+
+            let value = OsStr::from_bytes(b"y\xffs");
+            env::set_var("TRUTHY_TEST_VAR_BOOL_NOT_UNICODE", value);
+            let err = var_bool("TRUTHY_TEST_VAR_BOOL_NOT_UNICODE").unwrap_err();
+            assert_eq!(err.kind(), &EnvBoolErrorKind::NotUnicode(value.to_owned()));
+        }
+    }
+    mod var_bool_or {
+        use super::*;
+
+        #[test]
+        fn set() {
+            env::set_var("TRUTHY_TEST_VAR_BOOL_OR_SET", "no");
+            assert_eq!(var_bool_or("TRUTHY_TEST_VAR_BOOL_OR_SET", true), Ok(false));
+        }
+
+        #[test]
+        fn default() {
+            env::set_var("TRUTHY_TEST_VAR_BOOL_OR_EMPTY", "");
+            assert_eq!(var_bool_or("TRUTHY_TEST_VAR_BOOL_OR_UNSET", true), Ok(true));
+            assert_eq!(var_bool_or("TRUTHY_TEST_VAR_BOOL_OR_EMPTY", true), Ok(true));
+        }
+
+        #[test]
+        fn invalid() {
+            env::set_var("TRUTHY_TEST_VAR_BOOL_OR_INVALID", "maybe");
+            assert!(var_bool_or("TRUTHY_TEST_VAR_BOOL_OR_INVALID", true).is_err());
+        }
+    }
+}
@@ -0,0 +1,847 @@
+//! Boolean expressions that are parsed at runtime
+//!
+//! Expressions use the operators of [`truthy!`](crate::truthy): `!`, `^`, `&&`, `||`, `=>`,
+//! and parentheses. Operands are identifiers, and `true` and `false` are constants.
+//!
+//! Two operands can also be compared with `==`, `!=`, `<`, `<=`, `>`, or `>=`. Unlike in
+//! `truthy!`, where a comparison is part of an operand and compares the values themselves, these
+//! compare the truthiness of each side, where `false` is less than `true`. There are no number or
+//! string literals, so a rule like `count > 3` can't be written. Put the result of `count > 3`
+//! in the [`Context`] under its own identifier instead.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use truthy::Truthy;
+//! use truthy::expr::Expr;
+//!
+//! let rule: Expr = "beta && (admin || !trial)".parse().unwrap();
+//!
+//! let mut context: HashMap<String, Box<dyn Truthy>> = HashMap::new();
+//! context.insert("beta".into(), Box::new(true));
+//! context.insert("admin".into(), Box::new(0u8));
+//! context.insert("trial".into(), Box::new(Some(false)));
+//! assert_eq!(rule.eval(&context), Ok(true));
+//!
+//! let beta = 1u8;
+//! let context = |name: &str| match name {
+//!     "beta" => Some(&beta as &dyn Truthy),
+//!     _ => None,
+//! };
+//! assert!(rule.eval(&context).is_err());
+//! ```
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::ops::Range;
+use std::str::FromStr;
+
+use super::Truthy;
+
+pub use normalize::{TooManyVariables, TruthTable};
+
+mod normalize;
+
+/// A parsed expression
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Expr {
+    /// `true` or `false`
+    Const(bool),
+    /// An identifier, which is looked up in a [`Context`]
+    Var(String),
+    /// `!expr`
+    Not(Box<Expr>),
+    /// `left && right`
+    And(Box<Expr>, Box<Expr>),
+    /// `left || right`
+    Or(Box<Expr>, Box<Expr>),
+    /// `left ^ right`, which is true if exactly one side is true
+    ///
+    /// This is binary XOR, so a chain like `a ^ b ^ c` is true if an odd number of operands are
+    /// true.
+    Xor(Box<Expr>, Box<Expr>),
+    /// `left => right`, which is true unless `left` is true and `right` is false
+    Implies(Box<Expr>, Box<Expr>),
+    /// `left == right`, `left < right`, etc., which compare the truthiness of both sides
+    Compare(Comparison, Box<Expr>, Box<Expr>),
+}
+
+/// The operator of an [`Expr::Compare`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+impl Comparison {
+    /// Compares the truthiness of two operands, where `false` is less than `true`.
+    ///
+    /// ```
+    /// # use truthy::expr::Comparison;
+    /// assert!(Comparison::Lt.apply(false, true));
+    /// assert!(!Comparison::Ne.apply(true, true));
+    /// ```
+    pub fn apply(self, left: bool, right: bool) -> bool {
+        match self {
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+            Comparison::Lt => !left & right,
+            Comparison::Le => !left | right,
+            Comparison::Gt => left & !right,
+            Comparison::Ge => left | !right,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Comparison::Eq => "==",
+            Comparison::Ne => "!=",
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+        })
+    }
+}
+
+impl Expr {
+    /// Evaluates the expression, looking up identifiers in `context`.
+    ///
+    /// Like `&&` and `||` in Rust, the right side of `&&`, `||`, and `=>` isn't evaluated if the
+    /// left side decides the result, so identifiers on that side don't need to be in `context`.
+    pub fn eval<C: Context + ?Sized>(&self, context: &C) -> Result<bool, EvalError> {
+        match self {
+            Expr::Const(value) => Ok(*value),
+            Expr::Var(name) => context.get(name).ok_or_else(|| EvalError { name: name.clone() }),
+            Expr::Not(expr) => expr.eval(context).map(|value| !value),
+            Expr::And(left, right) => Ok(left.eval(context)? && right.eval(context)?),
+            Expr::Or(left, right) => Ok(left.eval(context)? || right.eval(context)?),
+            Expr::Xor(left, right) => Ok(left.eval(context)? != right.eval(context)?),
+            Expr::Implies(left, right) => Ok(!left.eval(context)? || right.eval(context)?),
+            Expr::Compare(op, left, right) => Ok(op.apply(left.eval(context)?, right.eval(context)?)),
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Implies(..) => 0,
+            Expr::Or(..) => 1,
+            Expr::And(..) => 2,
+            Expr::Xor(..) => 3,
+            Expr::Compare(..) => 4,
+            Expr::Not(_) => 5,
+            Expr::Const(_) | Expr::Var(_) => 6,
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, precedence: u8) -> fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    /// Writes the expression with as few parentheses as possible.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let expr: Expr = "(a && (b)) || !(c || d)".parse().unwrap();
+    /// assert_eq!(expr.to_string(), "a && b || !(c || d)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Const(value) => write!(f, "{}", value),
+            Expr::Var(name) => f.write_str(name),
+            Expr::Not(expr) => {
+                f.write_str("!")?;
+                expr.fmt_operand(f, 5)
+            }
+            Expr::And(left, right) => {
+                left.fmt_operand(f, 2)?;
+                f.write_str(" && ")?;
+                right.fmt_operand(f, 3)
+            }
+            Expr::Or(left, right) => {
+                left.fmt_operand(f, 1)?;
+                f.write_str(" || ")?;
+                right.fmt_operand(f, 2)
+            }
+            Expr::Xor(left, right) => {
+                left.fmt_operand(f, 3)?;
+                f.write_str(" ^ ")?;
+                right.fmt_operand(f, 4)
+            }
+            // Right-associative
+            Expr::Implies(left, right) => {
+                left.fmt_operand(f, 1)?;
+                f.write_str(" => ")?;
+                right.fmt_operand(f, 0)
+            }
+            // `!a == b` is an error, so a negated left side needs parentheses
+            Expr::Compare(op, left, right) => {
+                left.fmt_operand(f, 6)?;
+                write!(f, " {} ", op)?;
+                right.fmt_operand(f, 5)
+            }
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    /// Parses an expression.
+    ///
+    /// From loosest to tightest, the operators are `=>`, `||`, `&&`, `^`, comparisons, and `!`.
+    /// `=>` is right-associative, comparisons can't be chained, and the others are
+    /// left-associative. Like in [`truthy!`](crate::truthy), `!a == b` is an error, since it could
+    /// mean `!(a == b)` or `(!a) == b`.
+    ///
+    /// ```
+    /// # use truthy::expr::{Expr, ParseErrorKind};
+    /// let expr: Expr = "a || b && c".parse().unwrap();
+    /// assert_eq!(expr, Expr::Or(
+    ///     Box::new(Expr::Var("a".into())),
+    ///     Box::new(Expr::And(Box::new(Expr::Var("b".into())), Box::new(Expr::Var("c".into())))),
+    /// ));
+    ///
+    /// let error = "a && || b".parse::<Expr>().unwrap_err();
+    /// assert_eq!(error.kind(), &ParseErrorKind::ExpectedOperand);
+    /// assert_eq!(error.span(), 5..7);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { tokens: tokenize(s)?, pos: 0, len: s.len(), depth: 0, operators: 0 };
+        let expr = parser.implies()?;
+        let kind = match parser.tokens.get(parser.pos) {
+            Some((Token::Close, _)) => ParseErrorKind::UnmatchedClose,
+            Some(_) => ParseErrorKind::ExpectedOperator,
+            None => return Ok(expr),
+        };
+        Err(ParseError::new(kind, parser.tokens[parser.pos].1.clone()))
+    }
+}
+
+/// Looks up the identifiers in an [`Expr`].
+pub trait Context {
+    /// The truthiness of `name`, or `None` if it isn't defined.
+    fn get(&self, name: &str) -> Option<bool>;
+}
+
+impl<'a, F> Context for F
+where
+    F: Fn(&str) -> Option<&'a dyn Truthy>,
+{
+    fn get(&self, name: &str) -> Option<bool> {
+        self(name).map(Truthy::truthy)
+    }
+}
+
+impl<S: BuildHasher> Context for HashMap<String, Box<dyn Truthy>, S> {
+    fn get(&self, name: &str) -> Option<bool> {
+        HashMap::get(self, name).map(|value| value.truthy())
+    }
+}
+
+/// The error returned when an expression can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        ParseError { kind, span }
+    }
+
+    /// What went wrong
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// The byte range of the input where the error is
+    ///
+    /// If the input ended too early, this is an empty range at the end of the input.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {}
+
+/// The kinds of [`ParseError`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A character that isn't part of the grammar, like `+`, a single `&`, or a single `=`
+    InvalidChar(char),
+    /// A number or string, like `3` or `"app"`
+    ///
+    /// Comparisons compare the truthiness of identifiers, so there is nothing to compare a
+    /// literal with.
+    Literal,
+    /// Something other than an identifier, `!`, or `(` where one was expected
+    ExpectedOperand,
+    /// Something other than an operator, like `&&` or `||`, where one was expected
+    ExpectedOperator,
+    /// A `(` without a matching `)`
+    UnclosedParen,
+    /// A `)` without a matching `(`
+    UnmatchedClose,
+    /// A comparison after a `!`, like `!a == b`
+    ///
+    /// Like in [`truthy!`](crate::truthy), this must be written as `!(a == b)` or `(!a) == b`.
+    NegatedComparison,
+    /// More than 256 levels of nesting
+    ///
+    /// Each `(`, `!`, `=>`, and comparison adds a level. Chains like `a && b && c` don't, but
+    /// are limited by [`ParseErrorKind::TooManyOperands`].
+    TooDeep,
+    /// More than 1024 `&&`, `||`, and `^` operators in the whole expression
+    TooManyOperands,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidChar(c) => write!(f, "invalid character {:?}", c),
+            ParseErrorKind::Literal => {
+                write!(f, "numbers and strings can't be used, since comparisons compare truthiness")
+            }
+            ParseErrorKind::ExpectedOperand => write!(f, r#"expected an identifier, "!", or "(""#),
+            ParseErrorKind::ExpectedOperator => write!(f, r#"expected an operator, like "&&" or "||""#),
+            ParseErrorKind::UnclosedParen => write!(f, r#"unclosed "(""#),
+            ParseErrorKind::UnmatchedClose => write!(f, r#"unmatched ")""#),
+            ParseErrorKind::NegatedComparison => {
+                write!(f, r#"a comparison can't follow "!", use "!(a == b)" or "(!a) == b""#)
+            }
+            ParseErrorKind::TooDeep => write!(f, "expression is nested too deeply"),
+            ParseErrorKind::TooManyOperands => {
+                write!(f, r#"expression has more than 1024 "&&", "||", and "^" operators"#)
+            }
+        }
+    }
+}
+
+/// The error returned when an expression can't be evaluated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvalError {
+    name: String,
+}
+
+impl EvalError {
+    /// The identifier that isn't in the context
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown identifier {:?}", self.name)
+    }
+}
+
+impl Error for EvalError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Not,
+    And,
+    Or,
+    Xor,
+    Implies,
+    Compare(Comparison),
+    Open,
+    Close,
+}
+
+fn tokenize(s: &str) -> Result<Vec<(Token, Range<usize>)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '!' | '<' | '>' => {
+                let followed_by_eq = matches!(chars.peek(), Some(&(_, '=')));
+                if followed_by_eq {
+                    chars.next();
+                }
+                match (c, followed_by_eq) {
+                    ('!', false) => Token::Not,
+                    ('!', true) => Token::Compare(Comparison::Ne),
+                    ('<', false) => Token::Compare(Comparison::Lt),
+                    ('<', true) => Token::Compare(Comparison::Le),
+                    ('>', false) => Token::Compare(Comparison::Gt),
+                    _ => Token::Compare(Comparison::Ge),
+                }
+            }
+            '=' => match chars.peek() {
+                Some(&(_, '=')) => {
+                    chars.next();
+                    Token::Compare(Comparison::Eq)
+                }
+                Some(&(_, '>')) => {
+                    chars.next();
+                    Token::Implies
+                }
+                _ => return Err(ParseError::new(ParseErrorKind::InvalidChar('='), start..start + 1)),
+            },
+            '^' => Token::Xor,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '&' | '|' => {
+                match chars.peek() {
+                    Some(&(_, next)) if next == c => chars.next(),
+                    _ => {
+                        return Err(ParseError::new(ParseErrorKind::InvalidChar(c), start..start + 1))
+                    }
+                };
+                if c == '&' { Token::And } else { Token::Or }
+            }
+            c if c == '_' || c.is_alphabetic() => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c != '_' && !c.is_alphanumeric() {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                Token::Ident(s[start..end].into())
+            }
+            '0'..='9' | '"' | '\'' => {
+                let is_number = c.is_ascii_digit();
+                let mut end = start + 1;
+                while let Some(&(i, next)) = chars.peek() {
+                    if is_number && next != '.' && next != '_' && !next.is_alphanumeric() {
+                        break;
+                    }
+                    end = i + next.len_utf8();
+                    chars.next();
+                    // The closing quote
+                    if next == c {
+                        break;
+                    }
+                }
+                return Err(ParseError::new(ParseErrorKind::Literal, start..end));
+            }
+            c => {
+                let span = start..start + c.len_utf8();
+                return Err(ParseError::new(ParseErrorKind::InvalidChar(c), span));
+            }
+        };
+        let end = chars.peek().map_or(s.len(), |&(i, _)| i);
+        tokens.push((token, start..end));
+    }
+    Ok(tokens)
+}
+
+/// How deeply a parsed expression can be nested, so that recursing through it can't overflow the
+/// stack
+const MAX_DEPTH: usize = 256;
+
+/// How many `&&`, `||`, and `^` operators an expression can have. A chain like `a && b && c` is
+/// parsed as `(a && b) && c`, so this also limits how deeply it is nested.
+const MAX_OPERATORS: usize = 1024;
+
+struct Parser {
+    tokens: Vec<(Token, Range<usize>)>,
+    pos: usize,
+    len: usize,
+    depth: usize,
+    operators: usize,
+}
+
+impl Parser {
+    fn eat(&mut self, token: &Token) -> bool {
+        let matches = self.tokens.get(self.pos).map(|(t, _)| t) == Some(token);
+        if matches {
+            self.pos += 1;
+        }
+        matches
+    }
+
+    /// Adds a level of nesting for the token that was just eaten.
+    fn nest(&mut self) -> Result<(), ParseError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            let span = self.tokens[self.pos - 1].1.clone();
+            return Err(ParseError::new(ParseErrorKind::TooDeep, span));
+        }
+        Ok(())
+    }
+
+    /// Counts the `&&`, `||`, or `^` that was just eaten.
+    fn count_operator(&mut self) -> Result<(), ParseError> {
+        self.operators += 1;
+        if self.operators > MAX_OPERATORS {
+            let span = self.tokens[self.pos - 1].1.clone();
+            return Err(ParseError::new(ParseErrorKind::TooManyOperands, span));
+        }
+        Ok(())
+    }
+
+    fn implies(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.or()?;
+        if self.eat(&Token::Implies) {
+            self.nest()?;
+            let right = self.implies()?;
+            self.depth -= 1;
+            Ok(Expr::Implies(Box::new(expr), Box::new(right)))
+        } else {
+            Ok(expr)
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.and()?;
+        while self.eat(&Token::Or) {
+            self.count_operator()?;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.xor()?;
+        while self.eat(&Token::And) {
+            self.count_operator()?;
+            expr = Expr::And(Box::new(expr), Box::new(self.xor()?));
+        }
+        Ok(expr)
+    }
+
+    fn xor(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.compare()?;
+        while self.eat(&Token::Xor) {
+            self.count_operator()?;
+            expr = Expr::Xor(Box::new(expr), Box::new(self.compare()?));
+        }
+        Ok(expr)
+    }
+
+    fn compare(&mut self) -> Result<Expr, ParseError> {
+        let negated = matches!(self.tokens.get(self.pos), Some((Token::Not, _)));
+        let left = self.unary()?;
+        match self.tokens.get(self.pos) {
+            Some((Token::Compare(_), span)) if negated => {
+                Err(ParseError::new(ParseErrorKind::NegatedComparison, span.clone()))
+            }
+            Some(&(Token::Compare(op), _)) => {
+                self.pos += 1;
+                self.nest()?;
+                let right = self.unary()?;
+                self.depth -= 1;
+                Ok(Expr::Compare(op, Box::new(left), Box::new(right)))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.eat(&Token::Not) {
+            self.nest()?;
+            let expr = self.unary()?;
+            self.depth -= 1;
+            Ok(Expr::Not(Box::new(expr)))
+        } else {
+            self.atom()
+        }
+    }
+
+    fn atom(&mut self) -> Result<Expr, ParseError> {
+        let (token, span) = match self.tokens.get(self.pos) {
+            Some((token, span)) => (token.clone(), span.clone()),
+            None => {
+                return Err(ParseError::new(ParseErrorKind::ExpectedOperand, self.len..self.len))
+            }
+        };
+        self.pos += 1;
+        match token {
+            Token::Open => {
+                self.nest()?;
+                let expr = self.implies()?;
+                self.depth -= 1;
+                if self.eat(&Token::Close) {
+                    Ok(expr)
+                } else {
+                    Err(ParseError::new(ParseErrorKind::UnclosedParen, span))
+                }
+            }
+            Token::Ident(name) => Ok(match name.as_str() {
+                "true" => Expr::Const(true),
+                "false" => Expr::Const(false),
+                _ => Expr::Var(name),
+            }),
+            _ => Err(ParseError::new(ParseErrorKind::ExpectedOperand, span)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> Box<Expr> {
+        Box::new(Expr::Var(name.into()))
+    }
+
+    fn parse(s: &str) -> Expr {
+        s.parse().unwrap()
+    }
+
+    fn error(s: &str) -> (ParseErrorKind, Range<usize>) {
+        let error = s.parse::<Expr>().unwrap_err();
+        (error.kind, error.span)
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn precedence() {
+            assert_eq!(parse("a && b || c"), Expr::Or(Box::new(Expr::And(var("a"), var("b"))), var("c")));
+            assert_eq!(parse("!a && b"), Expr::And(Box::new(Expr::Not(var("a"))), var("b")));
+            assert_eq!(parse("a && (b || c)"), Expr::And(var("a"), Box::new(Expr::Or(var("b"), var("c")))));
+        }
+
+        #[test]
+        fn left_associative() {
+            assert_eq!(parse("a || b || c"), Expr::Or(Box::new(Expr::Or(var("a"), var("b"))), var("c")));
+            assert_eq!(parse("a ^ b ^ c"), Expr::Xor(Box::new(Expr::Xor(var("a"), var("b"))), var("c")));
+        }
+
+        #[test]
+        fn xor_and_implies() {
+            assert_eq!(parse("a ^ b && c"), Expr::And(Box::new(Expr::Xor(var("a"), var("b"))), var("c")));
+            assert_eq!(parse("!a ^ b"), Expr::Xor(Box::new(Expr::Not(var("a"))), var("b")));
+            assert_eq!(parse("a || b => c"), Expr::Implies(Box::new(Expr::Or(var("a"), var("b"))), var("c")));
+            // Right-associative
+            assert_eq!(parse("a => b => c"), Expr::Implies(var("a"), Box::new(Expr::Implies(var("b"), var("c")))));
+        }
+
+        #[test]
+        fn comparisons() {
+            let compare = |op, left, right| Expr::Compare(op, var(left), var(right));
+            assert_eq!(parse("a == b"), compare(Comparison::Eq, "a", "b"));
+            assert_eq!(parse("a!=b"), compare(Comparison::Ne, "a", "b"));
+            assert_eq!(parse("a < b"), compare(Comparison::Lt, "a", "b"));
+            assert_eq!(parse("a <= b"), compare(Comparison::Le, "a", "b"));
+            assert_eq!(parse("a > b"), compare(Comparison::Gt, "a", "b"));
+            assert_eq!(parse("a >= b"), compare(Comparison::Ge, "a", "b"));
+            assert_eq!(parse("(!a) == b"), Expr::Compare(Comparison::Eq, Box::new(Expr::Not(var("a"))), var("b")));
+            assert_eq!(parse("!(a == b)"), Expr::Not(Box::new(compare(Comparison::Eq, "a", "b"))));
+            assert_eq!(parse("a ^ b == c"), Expr::Xor(var("a"), Box::new(compare(Comparison::Eq, "b", "c"))));
+            assert_eq!(parse("a == !b"), Expr::Compare(Comparison::Eq, var("a"), Box::new(Expr::Not(var("b")))));
+        }
+
+        #[test]
+        fn constants() {
+            assert_eq!(parse("!true"), Expr::Not(Box::new(Expr::Const(true))));
+            assert_eq!(parse("false_flag"), *var("false_flag"));
+        }
+
+        #[test]
+        fn identifiers() {
+            assert_eq!(parse("_snake_case2"), *var("_snake_case2"));
+            assert_eq!(parse("  ünïcode  "), *var("ünïcode"));
+        }
+
+        #[test]
+        fn display_round_trip() {
+            for s in &["a && b || c", "a && (b || c)", "!(a && b)", "a || (b || c)", "!!a", "true && x"] {
+                assert_eq!(parse(s).to_string(), *s);
+            }
+            for s in &["a ^ b && c", "a ^ (b ^ c)", "(a => b) => c", "a => b => c", "!(a ^ b)"] {
+                assert_eq!(parse(s).to_string(), *s);
+            }
+            for s in &["(!a) == b", "!(a == b)", "a < (b && c)", "(a == b) == c", "a ^ b >= c"] {
+                assert_eq!(parse(s).to_string(), *s);
+            }
+        }
+    }
+
+    mod errors {
+        use super::*;
+
+        #[test]
+        fn invalid_char() {
+            assert_eq!(error("a & b"), (ParseErrorKind::InvalidChar('&'), 2..3));
+            assert_eq!(error("a + b"), (ParseErrorKind::InvalidChar('+'), 2..3));
+            assert_eq!(error("é€"), (ParseErrorKind::InvalidChar('€'), 2..5));
+            assert_eq!(error("a = b"), (ParseErrorKind::InvalidChar('='), 2..3));
+        }
+
+        #[test]
+        fn literal() {
+            assert_eq!(error("name && count > 3"), (ParseErrorKind::Literal, 16..17));
+            assert_eq!(error("1.5e3 <= a"), (ParseErrorKind::Literal, 0..5));
+            assert_eq!(error(r#"name == "app" || x"#), (ParseErrorKind::Literal, 8..13));
+            assert_eq!(error("a != 'é"), (ParseErrorKind::Literal, 5..8));
+            let error = "10".parse::<Expr>().unwrap_err();
+            let message = "numbers and strings can't be used, since comparisons compare truthiness";
+            assert_eq!(error.to_string(), format!("{} at 0..2", message));
+        }
+
+        #[test]
+        fn expected_operand() {
+            assert_eq!(error(""), (ParseErrorKind::ExpectedOperand, 0..0));
+            assert_eq!(error("a &&"), (ParseErrorKind::ExpectedOperand, 4..4));
+            assert_eq!(error("()"), (ParseErrorKind::ExpectedOperand, 1..2));
+        }
+
+        #[test]
+        fn expected_operator() {
+            assert_eq!(error("a b"), (ParseErrorKind::ExpectedOperator, 2..3));
+            assert_eq!(error("(a) !b"), (ParseErrorKind::ExpectedOperator, 4..5));
+            assert_eq!(error("a == b == c"), (ParseErrorKind::ExpectedOperator, 7..9));
+        }
+
+        #[test]
+        fn negated_comparison() {
+            assert_eq!(error("!a == b"), (ParseErrorKind::NegatedComparison, 3..5));
+            assert_eq!(error("x && !!a < b"), (ParseErrorKind::NegatedComparison, 9..10));
+            assert_eq!(error("!(a) >= b"), (ParseErrorKind::NegatedComparison, 5..7));
+            assert!("a != !b".parse::<Expr>().is_ok());
+        }
+
+        #[test]
+        fn parens() {
+            assert_eq!(error("a && (b || c"), (ParseErrorKind::UnclosedParen, 5..6));
+            assert_eq!(error("a)"), (ParseErrorKind::UnmatchedClose, 1..2));
+        }
+
+        #[test]
+        fn too_deep() {
+            let parens = "(".repeat(100_000) + "a" + &")".repeat(100_000);
+            assert_eq!(error(&parens), (ParseErrorKind::TooDeep, 256..257));
+            let nots = "!".repeat(100_000) + "a";
+            assert_eq!(error(&nots), (ParseErrorKind::TooDeep, 256..257));
+            let implies = vec!["a"; 100_000].join("=>");
+            assert_eq!(error(&implies), (ParseErrorKind::TooDeep, 769..771));
+        }
+
+        #[test]
+        fn deep_enough() {
+            let parens = "(".repeat(256) + "a" + &")".repeat(256);
+            assert_eq!(parse(&parens), Expr::Var("a".into()));
+            // As deep as the limits allow
+            let chain = vec!["a"; MAX_OPERATORS + 1].join(" && ");
+            let expr = parse(&("!".repeat(255) + "(" + &chain + ")"));
+            assert_eq!(expr.eval(&|_: &str| Some(&1 as &dyn Truthy)), Ok(false));
+            assert_eq!(expr.to_string().len(), 255 + 2 + chain.len());
+            assert_eq!(expr.simplify(), Expr::Not(Box::new(Expr::Var("a".into()))));
+        }
+
+        #[test]
+        fn too_many_operands() {
+            let chain = vec!["a"; MAX_OPERATORS + 1].join("||");
+            assert!(chain.parse::<Expr>().is_ok());
+            let chain = vec!["a"; MAX_OPERATORS + 2].join("||");
+            let end = chain.len() - 1;
+            assert_eq!(error(&chain), (ParseErrorKind::TooManyOperands, end - 2..end));
+            let chain = vec!["(a && b ^ c)"; 400].join("||");
+            assert_eq!(error(&chain).0, ParseErrorKind::TooManyOperands);
+            let chain = vec!["a"; 100_000].join("&&");
+            assert_eq!(error(&chain).0, ParseErrorKind::TooManyOperands);
+        }
+
+        #[test]
+        fn message() {
+            let error = "a &&".parse::<Expr>().unwrap_err();
+            assert_eq!(error.to_string(), r#"expected an identifier, "!", or "(" at 4..4"#);
+            let error = "a b".parse::<Expr>().unwrap_err();
+            assert_eq!(error.to_string(), r#"expected an operator, like "&&" or "||" at 2..3"#);
+        }
+    }
+
+    mod eval {
+        use super::*;
+
+        fn context() -> HashMap<String, Box<dyn Truthy>> {
+            let mut context: HashMap<String, Box<dyn Truthy>> = HashMap::new();
+            context.insert("yes".into(), Box::new(1u8));
+            context.insert("no".into(), Box::new(""));
+            context
+        }
+
+        #[test]
+        fn map() {
+            let context = context();
+            assert_eq!(parse("yes && !no").eval(&context), Ok(true));
+            assert_eq!(parse("no || !yes").eval(&context), Ok(false));
+            assert_eq!(parse("!(yes && no) && true").eval(&context), Ok(true));
+        }
+
+        #[test]
+        fn closure() {
+            let values = [0u32, 7];
+            let context = |name: &str| match name {
+                "zero" => Some(&values[0] as &dyn Truthy),
+                "seven" => Some(&values[1] as &dyn Truthy),
+                _ => None,
+            };
+            assert_eq!(parse("seven && !zero").eval(&context), Ok(true));
+            assert_eq!(parse("zero || false").eval(&context), Ok(false));
+        }
+
+        #[test]
+        fn unknown_identifier() {
+            let error = parse("yes && maybe").eval(&context()).unwrap_err();
+            assert_eq!(error.name(), "maybe");
+            assert_eq!(error.to_string(), r#"unknown identifier "maybe""#);
+        }
+
+        #[test]
+        fn xor_and_implies() {
+            let context = context();
+            assert_eq!(parse("yes ^ no").eval(&context), Ok(true));
+            assert_eq!(parse("yes ^ !no").eval(&context), Ok(false));
+            // Parity, not "exactly one"
+            assert_eq!(parse("yes ^ yes ^ yes").eval(&context), Ok(true));
+            assert_eq!(parse("yes => no").eval(&context), Ok(false));
+            assert_eq!(parse("no => no").eval(&context), Ok(true));
+        }
+
+        #[test]
+        fn comparisons() {
+            let context = context();
+            assert_eq!(parse("no < yes").eval(&context), Ok(true));
+            assert_eq!(parse("yes <= no").eval(&context), Ok(false));
+            assert_eq!(parse("yes == !no && no != no").eval(&context), Ok(false));
+            assert_eq!(parse("(!yes) > no").eval(&context), Ok(false));
+        }
+
+        #[test]
+        fn short_circuits() {
+            let context = context();
+            assert_eq!(parse("no && maybe").eval(&context), Ok(false));
+            assert_eq!(parse("yes || maybe").eval(&context), Ok(true));
+            assert_eq!(parse("no => maybe").eval(&context), Ok(true));
+            assert!(parse("yes ^ maybe").eval(&context).is_err());
+        }
+    }
+}
@@ -0,0 +1,856 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+
+use super::{Comparison, Expr};
+
+/// A literal in a clause of CNF or a term of DNF: a variable and whether it is not negated
+type Literal = (String, bool);
+
+/// The most identifiers that [`Expr::truth_table`] allows, whatever its `max_variables` is,
+/// since a table for more would have millions of rows
+const MAX_TABLE_VARIABLES: usize = 20;
+
+impl Expr {
+    /// The identifiers in the expression, sorted and without duplicates
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let expr: Expr = "b && (a || !b)".parse().unwrap();
+    /// assert_eq!(expr.variables(), ["a", "b"]);
+    /// ```
+    pub fn variables(&self) -> Vec<&str> {
+        let mut variables = BTreeSet::new();
+        self.collect_variables(&mut variables);
+        variables.into_iter().collect()
+    }
+
+    fn collect_variables<'a>(&'a self, variables: &mut BTreeSet<&'a str>) {
+        match self {
+            Expr::Const(_) => {}
+            Expr::Var(name) => {
+                variables.insert(name);
+            }
+            Expr::Not(expr) => expr.collect_variables(variables),
+            Expr::And(left, right)
+            | Expr::Or(left, right)
+            | Expr::Xor(left, right)
+            | Expr::Implies(left, right)
+            | Expr::Compare(_, left, right) => {
+                left.collect_variables(variables);
+                right.collect_variables(variables);
+            }
+        }
+    }
+
+    /// Converts to negation normal form, where `!` is only applied to identifiers, and the only
+    /// other operators are `&&`, `||`, and `^`.
+    ///
+    /// `^`, `==`, and `!=` are kept as `^`, since rewriting them with `&&` and `||` repeats both
+    /// operands, which makes a chain like `a ^ b ^ c` exponentially larger. Constants are removed
+    /// unless the whole expression is a constant.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let expr: Expr = "!(a && !(b && c))".parse().unwrap();
+    /// assert_eq!(expr.to_nnf().to_string(), "!a || b && c");
+    ///
+    /// let expr: Expr = "!(a == b) || c > d".parse().unwrap();
+    /// assert_eq!(expr.to_nnf().to_string(), "a ^ b || c && !d");
+    /// ```
+    pub fn to_nnf(&self) -> Expr {
+        self.nnf(true, true)
+    }
+
+    /// Negation normal form of `self` if `positive`, or of `!self` if not, where `^` is only
+    /// kept if `keep_xor`
+    fn nnf(&self, positive: bool, keep_xor: bool) -> Expr {
+        match self {
+            Expr::Const(value) => Expr::Const(*value == positive),
+            Expr::Var(_) if positive => self.clone(),
+            Expr::Var(_) => Expr::Not(Box::new(self.clone())),
+            Expr::Not(expr) => expr.nnf(!positive, keep_xor),
+            Expr::And(left, right) | Expr::Or(left, right) => {
+                let is_and = matches!(self, Expr::And(..)) == positive;
+                let (left, right) = (left.nnf(positive, keep_xor), right.nnf(positive, keep_xor));
+                match (left, right) {
+                    (Expr::Const(value), other) | (other, Expr::Const(value)) => {
+                        if value == is_and {
+                            other
+                        } else {
+                            Expr::Const(value)
+                        }
+                    }
+                    (left, right) if is_and => Expr::And(Box::new(left), Box::new(right)),
+                    (left, right) => Expr::Or(Box::new(left), Box::new(right)),
+                }
+            }
+            Expr::Xor(left, right)
+            | Expr::Compare(Comparison::Eq, left, right)
+            | Expr::Compare(Comparison::Ne, left, right)
+                if keep_xor =>
+            {
+                // `!(a ^ b)` and `a == b` are both `a ^ !b`, so only the right side is negated
+                let negate = matches!(self, Expr::Compare(Comparison::Eq, ..)) == positive;
+                match (left.nnf(true, true), right.nnf(!negate, true)) {
+                    (Expr::Const(value), _) => right.nnf(value == negate, true),
+                    (_, Expr::Const(value)) => left.nnf(!value, true),
+                    (left, right) => Expr::Xor(Box::new(left), Box::new(right)),
+                }
+            }
+            Expr::Xor(..) | Expr::Implies(..) | Expr::Compare(..) => {
+                self.desugar().nnf(positive, keep_xor)
+            }
+        }
+    }
+
+    /// Rewrites `^`, `=>`, or a comparison with `!`, `&&`, and `||`.
+    fn desugar(&self) -> Expr {
+        let not = |expr: &Expr| Expr::Not(Box::new(expr.clone()));
+        let and = |left: Expr, right: Expr| Expr::And(Box::new(left), Box::new(right));
+        let or = |left: Expr, right: Expr| Expr::Or(Box::new(left), Box::new(right));
+        match self {
+            Expr::Xor(a, b) | Expr::Compare(Comparison::Ne, a, b) => {
+                or(and((**a).clone(), not(b)), and(not(a), (**b).clone()))
+            }
+            Expr::Compare(Comparison::Eq, a, b) => {
+                or(and((**a).clone(), (**b).clone()), and(not(a), not(b)))
+            }
+            Expr::Implies(a, b) | Expr::Compare(Comparison::Le, a, b) => or(not(a), (**b).clone()),
+            Expr::Compare(Comparison::Lt, a, b) => and(not(a), (**b).clone()),
+            Expr::Compare(Comparison::Gt, a, b) => and((**a).clone(), not(b)),
+            Expr::Compare(Comparison::Ge, a, b) => or((**a).clone(), not(b)),
+            _ => self.clone(),
+        }
+    }
+
+    /// Rebuilds a binary operator with new operands.
+    fn with_operands(&self, left: Expr, right: Expr) -> Expr {
+        let (left, right) = (Box::new(left), Box::new(right));
+        match self {
+            Expr::And(..) => Expr::And(left, right),
+            Expr::Or(..) => Expr::Or(left, right),
+            Expr::Xor(..) => Expr::Xor(left, right),
+            Expr::Implies(..) => Expr::Implies(left, right),
+            Expr::Compare(op, ..) => Expr::Compare(*op, left, right),
+            _ => unreachable!("not a binary operator"),
+        }
+    }
+
+    /// Converts to conjunctive normal form, which is an `&&` of `||`s of identifiers or
+    /// negated identifiers.
+    ///
+    /// Clauses that are always true and clauses that contain another clause are removed.
+    /// The result can be exponentially larger than `self`.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let expr: Expr = "a || b && c".parse().unwrap();
+    /// assert_eq!(expr.to_cnf().to_string(), "(a || b) && (a || c)");
+    /// ```
+    pub fn to_cnf(&self) -> Expr {
+        let clauses = reduce(self.nnf(true, false).cnf());
+        let clauses = clauses.into_iter().map(|clause| join(clause, false));
+        fold(clauses, true)
+    }
+
+    /// Converts to disjunctive normal form, which is an `||` of `&&`s of identifiers or
+    /// negated identifiers.
+    ///
+    /// Terms that are always false and terms that contain another term are removed.
+    /// The result can be exponentially larger than `self`.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let expr: Expr = "(a || b) && !a".parse().unwrap();
+    /// assert_eq!(expr.to_dnf().to_string(), "b && !a");
+    /// ```
+    pub fn to_dnf(&self) -> Expr {
+        let terms = reduce(self.nnf(true, false).dnf());
+        let terms = terms.into_iter().map(|term| join(term, true));
+        fold(terms, false)
+    }
+
+    /// The clauses of an expression in NNF without `^`
+    fn cnf(&self) -> Vec<Vec<Literal>> {
+        match self {
+            Expr::And(left, right) => {
+                let mut clauses = left.cnf();
+                clauses.extend(right.cnf());
+                clauses
+            }
+            Expr::Or(left, right) => product(left.cnf(), right.cnf()),
+            Expr::Const(true) => Vec::new(),
+            Expr::Const(false) => vec![Vec::new()],
+            literal => vec![vec![literal.literal()]],
+        }
+    }
+
+    /// The terms of an expression in NNF without `^`
+    fn dnf(&self) -> Vec<Vec<Literal>> {
+        match self {
+            Expr::Or(left, right) => {
+                let mut terms = left.dnf();
+                terms.extend(right.dnf());
+                terms
+            }
+            Expr::And(left, right) => product(left.dnf(), right.dnf()),
+            Expr::Const(false) => Vec::new(),
+            Expr::Const(true) => vec![Vec::new()],
+            literal => vec![vec![literal.literal()]],
+        }
+    }
+
+    fn literal(&self) -> Literal {
+        match self {
+            Expr::Var(name) => (name.clone(), true),
+            Expr::Not(expr) => match &**expr {
+                Expr::Var(name) => (name.clone(), false),
+                _ => unreachable!("not in negation normal form"),
+            },
+            _ => unreachable!("not a literal"),
+        }
+    }
+
+    /// Simplifies the expression without changing its result.
+    ///
+    /// Constants are folded, double negations are removed, and chains of `&&` or `||` lose
+    /// repeated operands. A chain that contains both `x` and `!x` becomes a constant, and
+    /// operands that are absorbed by another operand, like `x || y` in `x && (x || y)`, are
+    /// removed. `^`, `=>`, and comparisons are kept unless an operand is a constant, or both
+    /// operands are the same.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let simplify = |s: &str| s.parse::<Expr>().unwrap().simplify().to_string();
+    /// assert_eq!(simplify("x && !x"), "false");
+    /// assert_eq!(simplify("a || !!b || a"), "a || b");
+    /// assert_eq!(simplify("a && (a || b) && true"), "a");
+    /// assert_eq!(simplify("a ^ true => b"), "!a => b");
+    /// assert_eq!(simplify("a == a"), "true");
+    /// ```
+    pub fn simplify(&self) -> Expr {
+        match self {
+            Expr::Const(_) | Expr::Var(_) => self.clone(),
+            Expr::Not(expr) => match expr.simplify() {
+                Expr::Const(value) => Expr::Const(!value),
+                Expr::Not(expr) => *expr,
+                expr => Expr::Not(Box::new(expr)),
+            },
+            Expr::And(..) | Expr::Or(..) => {
+                let is_and = matches!(self, Expr::And(..));
+                let mut operands = Vec::new();
+                for operand in self.chain(is_and) {
+                    let operand = operand.simplify();
+                    match operand {
+                        Expr::Const(value) if value == is_and => {}
+                        Expr::Const(_) => return operand,
+                        operand if operand.is_chain(is_and) => {
+                            operands.extend(operand.chain(is_and).into_iter().cloned())
+                        }
+                        operand => operands.push(operand),
+                    }
+                }
+                let mut kept: Vec<Expr> = Vec::new();
+                for operand in operands {
+                    if kept.iter().any(|other| other.is_negation_of(&operand)) {
+                        return Expr::Const(!is_and);
+                    }
+                    if !kept.contains(&operand) {
+                        kept.push(operand);
+                    }
+                }
+                let absorbed = |operand: &Expr| {
+                    operand.is_chain(!is_and)
+                        && operand.chain(!is_and).iter().any(|inner| kept.contains(*inner))
+                };
+                let kept: Vec<Expr> = kept.iter().filter(|operand| !absorbed(operand)).cloned().collect();
+                fold(kept.into_iter(), is_and)
+            }
+            Expr::Xor(left, right) | Expr::Implies(left, right) | Expr::Compare(_, left, right) => {
+                let (left, right) = (left.simplify(), right.simplify());
+                if matches!(left, Expr::Const(_)) || matches!(right, Expr::Const(_)) {
+                    self.with_operands(left, right).desugar().simplify()
+                } else if left == right {
+                    // Each of these operators has the same result for `(false, false)` and
+                    // `(true, true)`
+                    let both_true = self.with_operands(Expr::Const(true), Expr::Const(true));
+                    Expr::Const(both_true.eval_with(&[], &[]))
+                } else {
+                    self.with_operands(left, right)
+                }
+            }
+        }
+    }
+
+    fn is_chain(&self, is_and: bool) -> bool {
+        match self {
+            Expr::And(..) => is_and,
+            Expr::Or(..) => !is_and,
+            _ => false,
+        }
+    }
+
+    /// The operands of a chain of `&&`s if `is_and`, or `||`s if not
+    fn chain(&self, is_and: bool) -> Vec<&Expr> {
+        match self {
+            Expr::And(left, right) | Expr::Or(left, right) if self.is_chain(is_and) => {
+                let mut operands = left.chain(is_and);
+                operands.extend(right.chain(is_and));
+                operands
+            }
+            _ => vec![self],
+        }
+    }
+
+    fn is_negation_of(&self, other: &Expr) -> bool {
+        match (self, other) {
+            (Expr::Not(expr), other) | (other, Expr::Not(expr)) => **expr == *other,
+            _ => false,
+        }
+    }
+
+    /// Checks if the expression is `true` for every value of its identifiers.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// assert!("a || !a".parse::<Expr>().unwrap().is_tautology());
+    /// assert!(!"a || b".parse::<Expr>().unwrap().is_tautology());
+    /// ```
+    pub fn is_tautology(&self) -> bool {
+        !Expr::Not(Box::new(self.clone())).is_satisfiable()
+    }
+
+    /// Checks if the expression is `false` for every value of its identifiers.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// assert!("a && (b && !a)".parse::<Expr>().unwrap().is_contradiction());
+    /// assert!(!"a && b".parse::<Expr>().unwrap().is_contradiction());
+    /// ```
+    pub fn is_contradiction(&self) -> bool {
+        !self.is_satisfiable()
+    }
+
+    /// Checks if the expression is `true` for any values of its identifiers, by trying both
+    /// values of one identifier at a time.
+    fn is_satisfiable(&self) -> bool {
+        match self.simplify() {
+            Expr::Const(value) => value,
+            expr => {
+                let name = expr.variables()[0].to_string();
+                [true, false].iter().any(|&value| expr.assign(&name, value).is_satisfiable())
+            }
+        }
+    }
+
+    /// Replaces the identifier `name` with a constant.
+    fn assign(&self, name: &str, value: bool) -> Expr {
+        match self {
+            Expr::Var(var) if var == name => Expr::Const(value),
+            Expr::Const(_) | Expr::Var(_) => self.clone(),
+            Expr::Not(expr) => Expr::Not(Box::new(expr.assign(name, value))),
+            Expr::And(left, right)
+            | Expr::Or(left, right)
+            | Expr::Xor(left, right)
+            | Expr::Implies(left, right)
+            | Expr::Compare(_, left, right) => {
+                self.with_operands(left.assign(name, value), right.assign(name, value))
+            }
+        }
+    }
+
+    /// Evaluates the expression, where `values[i]` is the value of `variables[i]`.
+    fn eval_with(&self, variables: &[&str], values: &[bool]) -> bool {
+        let eval = |expr: &Expr| expr.eval_with(variables, values);
+        match self {
+            Expr::Const(value) => *value,
+            Expr::Var(name) => values[variables.iter().position(|var| var == name).unwrap()],
+            Expr::Not(expr) => !eval(expr),
+            Expr::And(left, right) => eval(left) && eval(right),
+            Expr::Or(left, right) => eval(left) || eval(right),
+            Expr::Xor(left, right) => eval(left) != eval(right),
+            Expr::Implies(left, right) => !eval(left) || eval(right),
+            Expr::Compare(op, left, right) => op.apply(eval(left), eval(right)),
+        }
+    }
+
+    /// Evaluates the expression for every combination of values of its identifiers.
+    ///
+    /// Returns an error if there are more than `max_variables` identifiers, since the table
+    /// has `2^n` rows for `n` identifiers. `max_variables` is capped at 20, which is already
+    /// over a million rows.
+    ///
+    /// ```
+    /// # use truthy::expr::Expr;
+    /// let expr: Expr = "a && !b".parse().unwrap();
+    /// let table = expr.truth_table(8).unwrap();
+    /// assert_eq!(table.variables(), ["a", "b"]);
+    /// assert_eq!(table.rows()[2], (vec![true, false], true));
+    /// assert_eq!(table.to_string(), "\
+    /// a     | b     | result
+    /// false | false | false
+    /// false | true  | false
+    /// true  | false | true
+    /// true  | true  | false
+    /// ");
+    ///
+    /// assert!(expr.truth_table(1).is_err());
+    /// ```
+    pub fn truth_table(&self, max_variables: usize) -> Result<TruthTable, TooManyVariables> {
+        let variables = self.variables();
+        let max_variables = max_variables.min(MAX_TABLE_VARIABLES);
+        if variables.len() > max_variables {
+            return Err(TooManyVariables { variables: variables.len(), max: max_variables });
+        }
+        let count = variables.len();
+        let rows = (0..1usize << count)
+            .map(|row| {
+                let bit = |i: usize| (row >> (count - 1 - i)) & 1 == 1;
+                let values: Vec<bool> = (0..count).map(bit).collect();
+                let result = self.eval_with(&variables, &values);
+                (values, result)
+            })
+            .collect();
+        let variables = variables.into_iter().map(String::from).collect();
+        Ok(TruthTable { variables, rows })
+    }
+}
+
+/// Every way to pick one list from `left` and one from `right`, joined together
+fn product(left: Vec<Vec<Literal>>, right: Vec<Vec<Literal>>) -> Vec<Vec<Literal>> {
+    let mut lists = Vec::new();
+    for l in &left {
+        for r in &right {
+            lists.push(l.iter().chain(r).cloned().collect());
+        }
+    }
+    lists
+}
+
+/// Removes repeated literals, lists that contain a literal and its negation, and lists that
+/// contain another list.
+fn reduce(lists: Vec<Vec<Literal>>) -> Vec<Vec<Literal>> {
+    let lists: Vec<Vec<Literal>> = lists
+        .into_iter()
+        .filter_map(|list| {
+            let mut deduped: Vec<Literal> = Vec::new();
+            for literal in list {
+                let negated = (literal.0.clone(), !literal.1);
+                if deduped.contains(&negated) {
+                    return None;
+                }
+                if !deduped.contains(&literal) {
+                    deduped.push(literal);
+                }
+            }
+            Some(deduped)
+        })
+        .collect();
+    let contains = |outer: &Vec<Literal>, inner: &Vec<Literal>| {
+        inner.iter().all(|literal| outer.contains(literal))
+    };
+    let mut kept: Vec<Vec<Literal>> = Vec::new();
+    for (i, list) in lists.iter().enumerate() {
+        let redundant = lists.iter().enumerate().any(|(j, other)| {
+            j != i && contains(list, other) && (!contains(other, list) || j < i)
+        });
+        if !redundant {
+            kept.push(list.clone());
+        }
+    }
+    kept
+}
+
+/// Joins literals with `&&` if `is_and`, or `||` if not
+fn join(literals: Vec<Literal>, is_and: bool) -> Expr {
+    let literals = literals.into_iter().map(|(name, positive)| {
+        let var = Expr::Var(name);
+        if positive {
+            var
+        } else {
+            Expr::Not(Box::new(var))
+        }
+    });
+    fold(literals, is_and)
+}
+
+/// Joins expressions with `&&` if `is_and`, or `||` if not, or returns the identity of the
+/// operator if there are no expressions
+fn fold<I: Iterator<Item = Expr>>(mut exprs: I, is_and: bool) -> Expr {
+    let first = match exprs.next() {
+        Some(first) => first,
+        None => return Expr::Const(is_and),
+    };
+    exprs.fold(first, |left, right| {
+        if is_and {
+            Expr::And(Box::new(left), Box::new(right))
+        } else {
+            Expr::Or(Box::new(left), Box::new(right))
+        }
+    })
+}
+
+/// The result of [`Expr::truth_table`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TruthTable {
+    variables: Vec<String>,
+    rows: Vec<(Vec<bool>, bool)>,
+}
+
+impl TruthTable {
+    /// The identifiers in the expression, which are the columns of the table
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// The value of each identifier, and the result of the expression
+    ///
+    /// The first row has every identifier set to `false`, and the last row has every
+    /// identifier set to `true`.
+    pub fn rows(&self) -> &[(Vec<bool>, bool)] {
+        &self.rows
+    }
+}
+
+impl fmt::Display for TruthTable {
+    /// Writes the table with a column for each identifier, and a column for the result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Wide enough for "false"
+        let widths: Vec<usize> =
+            self.variables.iter().map(|name| name.chars().count().max(5)).collect();
+        for (name, width) in self.variables.iter().zip(&widths) {
+            write!(f, "{:width$} | ", name, width = width)?;
+        }
+        writeln!(f, "result")?;
+        for (values, result) in &self.rows {
+            for (value, width) in values.iter().zip(&widths) {
+                write!(f, "{:width$} | ", value, width = width)?;
+            }
+            writeln!(f, "{}", result)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when an expression has too many identifiers for a truth table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TooManyVariables {
+    variables: usize,
+    max: usize,
+}
+
+impl TooManyVariables {
+    /// The number of identifiers in the expression
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// The maximum that was allowed
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl fmt::Display for TooManyVariables {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expression has {} identifiers, but the maximum is {}", self.variables, self.max)
+    }
+}
+
+impl Error for TooManyVariables {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Expr {
+        s.parse().unwrap()
+    }
+
+    /// Checks that `converted` has the same truth table as `original`.
+    fn assert_equivalent(original: &Expr, converted: &Expr) {
+        let variables = original.variables();
+        assert!(converted.variables().iter().all(|var| variables.contains(var)));
+        let table = original.truth_table(16).unwrap();
+        for (values, result) in table.rows() {
+            assert_eq!(converted.eval_with(&variables, values), *result, "{} and {}", original, converted);
+        }
+    }
+
+    const EXPRESSIONS: &[&str] = &[
+        "a",
+        "!a",
+        "a && b || c",
+        "!(a || b) && (c || !d)",
+        "(a || b) && (a || !b) && (c || a)",
+        "!(a && !(b || !(c && d)))",
+        "x && !x || y",
+        "true && a || false",
+        "!true",
+        "a ^ b => c",
+        "a == !b && (c < d)",
+        "(a => b) ^ (b >= c) || a != d",
+        "a > (b => false)",
+    ];
+
+    fn is_literal(expr: &Expr) -> bool {
+        match expr {
+            Expr::Var(_) => true,
+            Expr::Not(expr) => matches!(**expr, Expr::Var(_)),
+            _ => false,
+        }
+    }
+
+    mod nnf {
+        use super::*;
+
+        fn is_nnf(expr: &Expr) -> bool {
+            match expr {
+                Expr::And(left, right) | Expr::Or(left, right) | Expr::Xor(left, right) => {
+                    is_nnf(left) && is_nnf(right)
+                }
+                expr => is_literal(expr),
+            }
+        }
+
+        #[test]
+        fn equivalent() {
+            for s in EXPRESSIONS {
+                let expr = parse(s);
+                let nnf = expr.to_nnf();
+                assert!(is_nnf(&nnf) || matches!(nnf, Expr::Const(_)), "{}", nnf);
+                assert_equivalent(&expr, &nnf);
+            }
+        }
+
+        #[test]
+        fn de_morgan() {
+            assert_eq!(parse("!(a || b)").to_nnf(), parse("!a && !b"));
+            assert_eq!(parse("!(!a && b)").to_nnf(), parse("a || !b"));
+        }
+
+        #[test]
+        fn constants() {
+            assert_eq!(parse("!false").to_nnf(), Expr::Const(true));
+            assert_eq!(parse("a && !false").to_nnf(), parse("a"));
+            assert_eq!(parse("a ^ true").to_nnf(), parse("!a"));
+            assert_eq!(parse("!(false == a)").to_nnf(), parse("a"));
+            assert_eq!(parse("(a || !a) != !b").to_nnf(), parse("(a || !a) ^ !b"));
+        }
+
+        #[test]
+        fn keeps_xor() {
+            assert_eq!(parse("!(a ^ b)").to_nnf(), parse("a ^ !b"));
+            assert_eq!(parse("a == !b").to_nnf(), parse("a ^ b"));
+            assert_eq!(parse("!(a != b)").to_nnf(), parse("a ^ !b"));
+            for s in &["a ^ true", "!(false == a)", "!(a ^ b) && (c == d)", "a != !b ^ (c == true)"] {
+                let expr = parse(s);
+                let nnf = expr.to_nnf();
+                assert!(is_nnf(&nnf), "{}", nnf);
+                assert_equivalent(&expr, &nnf);
+            }
+            // Not exponentially larger
+            let names: Vec<String> = (0..40).map(|i| format!("v{}", i)).collect();
+            let chain = parse(&format!("!({})", names.join(" ^ ")));
+            assert_eq!(chain.to_nnf().variables().len(), 40);
+            assert!(chain.to_nnf().to_string().len() < 2 * chain.to_string().len());
+        }
+    }
+
+    mod cnf {
+        use super::*;
+
+        fn is_clause(expr: &Expr) -> bool {
+            match expr {
+                Expr::Or(left, right) => is_clause(left) && is_clause(right),
+                expr => is_literal(expr),
+            }
+        }
+
+        fn is_cnf(expr: &Expr) -> bool {
+            match expr {
+                Expr::And(left, right) => is_cnf(left) && is_cnf(right),
+                Expr::Const(_) => true,
+                expr => is_clause(expr),
+            }
+        }
+
+        #[test]
+        fn equivalent() {
+            for s in EXPRESSIONS {
+                let expr = parse(s);
+                let cnf = expr.to_cnf();
+                assert!(is_cnf(&cnf), "{}", cnf);
+                assert_equivalent(&expr, &cnf);
+            }
+        }
+
+        #[test]
+        fn distributes() {
+            assert_eq!(parse("a && b || c && d").to_cnf().to_string(), "(a || c) && (a || d) && (b || c) && (b || d)");
+        }
+
+        #[test]
+        fn removes_redundant_clauses() {
+            assert_eq!(parse("a || !a").to_cnf(), Expr::Const(true));
+            assert_eq!(parse("a && (a || b)").to_cnf(), parse("a"));
+        }
+    }
+
+    mod dnf {
+        use super::*;
+
+        fn is_term(expr: &Expr) -> bool {
+            match expr {
+                Expr::And(left, right) => is_term(left) && is_term(right),
+                expr => is_literal(expr),
+            }
+        }
+
+        fn is_dnf(expr: &Expr) -> bool {
+            match expr {
+                Expr::Or(left, right) => is_dnf(left) && is_dnf(right),
+                Expr::Const(_) => true,
+                expr => is_term(expr),
+            }
+        }
+
+        #[test]
+        fn equivalent() {
+            for s in EXPRESSIONS {
+                let expr = parse(s);
+                let dnf = expr.to_dnf();
+                assert!(is_dnf(&dnf), "{}", dnf);
+                assert_equivalent(&expr, &dnf);
+            }
+        }
+
+        #[test]
+        fn distributes() {
+            assert_eq!(parse("(a || b) && (c || d)").to_dnf().to_string(), "a && c || a && d || b && c || b && d");
+        }
+
+        #[test]
+        fn removes_redundant_terms() {
+            assert_eq!(parse("a && !a").to_dnf(), Expr::Const(false));
+            assert_eq!(parse("a || a && b").to_dnf(), parse("a"));
+        }
+    }
+
+    mod simplify {
+        use super::*;
+
+        #[test]
+        fn equivalent() {
+            for s in EXPRESSIONS {
+                let expr = parse(s);
+                assert_equivalent(&expr, &expr.simplify());
+            }
+        }
+
+        #[test]
+        fn complements() {
+            assert_eq!(parse("x && !x").simplify(), Expr::Const(false));
+            assert_eq!(parse("!x || y || x").simplify(), Expr::Const(true));
+            assert_eq!(parse("(a || b) && !(a || b)").simplify(), Expr::Const(false));
+        }
+
+        #[test]
+        fn constants() {
+            assert_eq!(parse("a && true").simplify(), parse("a"));
+            assert_eq!(parse("a && false").simplify(), Expr::Const(false));
+            assert_eq!(parse("!(a || true)").simplify(), Expr::Const(false));
+        }
+
+        #[test]
+        fn double_negation() {
+            assert_eq!(parse("!!!a").simplify(), parse("!a"));
+        }
+
+        #[test]
+        fn duplicates() {
+            assert_eq!(parse("a || b || a").simplify(), parse("a || b"));
+            assert_eq!(parse("(a || b) && (a || b)").simplify(), parse("a || b"));
+        }
+
+        #[test]
+        fn extended_operators() {
+            assert_eq!(parse("a ^ false").simplify(), parse("a"));
+            assert_eq!(parse("true => a && a").simplify(), parse("a"));
+            assert_eq!(parse("a < true").simplify(), parse("!a"));
+            assert_eq!(parse("(a || b) != (a || b || a)").simplify(), Expr::Const(false));
+            assert_eq!(parse("(!!a) >= b").simplify(), parse("a >= b"));
+        }
+
+        #[test]
+        fn absorption() {
+            assert_eq!(parse("a || a && b").simplify(), parse("a"));
+            assert_eq!(parse("(b || a) && a && c").simplify(), parse("a && c"));
+        }
+    }
+
+    mod decide {
+        use super::*;
+
+        #[test]
+        fn tautology() {
+            assert!(parse("true").is_tautology());
+            assert!(parse("a || !a").is_tautology());
+            assert!(parse("!(a && b) || a").is_tautology());
+            assert!(!parse("a || b").is_tautology());
+            assert!(!parse("false").is_tautology());
+            assert!(parse("a && b => a").is_tautology());
+            assert!(parse("a ^ b == (a != b)").is_tautology());
+        }
+
+        #[test]
+        fn contradiction() {
+            assert!(parse("false").is_contradiction());
+            assert!(parse("(a || b) && !a && !b").is_contradiction());
+            assert!(!parse("a && !b").is_contradiction());
+            assert!(!parse("true").is_contradiction());
+        }
+    }
+
+    mod truth_table {
+        use super::*;
+
+        #[test]
+        fn rows() {
+            let table = parse("a || b").truth_table(2).unwrap();
+            let results: Vec<bool> = table.rows().iter().map(|(_, result)| *result).collect();
+            assert_eq!(results, [false, true, true, true]);
+        }
+
+        #[test]
+        fn constant() {
+            let table = parse("!false").truth_table(0).unwrap();
+            assert!(table.variables().is_empty());
+            assert_eq!(table.rows(), [(vec![], true)]);
+            assert_eq!(table.to_string(), "result\ntrue\n");
+        }
+
+        #[test]
+        fn wide_names() {
+            let table = parse("enabled").truth_table(1).unwrap();
+            assert_eq!(table.to_string(), "enabled | result\nfalse   | false\ntrue    | true\n");
+        }
+
+        #[test]
+        fn too_many_variables() {
+            let error = parse("a && b && c").truth_table(2).unwrap_err();
+            assert_eq!((error.variables(), error.max()), (3, 2));
+            assert_eq!(error.to_string(), "expression has 3 identifiers, but the maximum is 2");
+        }
+
+        #[test]
+        fn max_too_large() {
+            let names: Vec<String> = (0..64).map(|i| format!("v{}", i)).collect();
+            let error = parse(&names.join(" && ")).truth_table(usize::MAX).unwrap_err();
+            assert_eq!((error.variables(), error.max()), (64, MAX_TABLE_VARIABLES));
+            let error = parse(&names[..21].join(" && ")).truth_table(40).unwrap_err();
+            assert_eq!((error.variables(), error.max()), (21, 20));
+        }
+    }
+}
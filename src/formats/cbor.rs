@@ -0,0 +1,109 @@
+use ciborium::value::Value;
+
+use crate::policy::Policy;
+use crate::Truthy;
+
+/// Tags for dates and times, from RFC 8949 and RFC 8943
+const DATETIME_TAGS: &[u64] = &[0, 1, 100, 1004];
+
+impl Truthy for Value {
+    /// Follows the rules for the contained value
+    ///
+    /// `null` is falsy, numbers are truthy if not `0`, and bytes, strings, arrays, and maps are
+    /// truthy if not empty. Tagged values follow the rules for the value that they tag, except
+    /// for dates and times, which are always truthy.
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// use ciborium::value::Value;
+    ///
+    /// assert!(Value::Null.falsy());
+    /// assert!(Value::Tag(24, Box::new(Value::Bytes(vec![]))).falsy());
+    /// assert!(Value::Tag(1, Box::new(Value::Integer(0.into()))).truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Integer(i) => i128::from(*i).truthy(),
+            Value::Bytes(b) => b[..].truthy(),
+            Value::Float(f) => f.truthy(),
+            Value::Text(s) => s.truthy(),
+            Value::Bool(b) => *b,
+            Value::Null => false,
+            Value::Tag(tag, _) if DATETIME_TAGS.contains(tag) => true,
+            Value::Tag(_, v) => v.truthy(),
+            Value::Array(a) => a[..].truthy(),
+            Value::Map(m) => m[..].truthy(),
+            _ => true,
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        match self {
+            Value::Integer(i) => i128::from(*i).truthy_under(policy),
+            Value::Bytes(b) => b[..].truthy_under(policy),
+            Value::Float(f) => f.truthy_under(policy),
+            Value::Text(s) => policy.str(s),
+            Value::Bool(b) => *b,
+            Value::Null => false,
+            Value::Tag(tag, _) if DATETIME_TAGS.contains(tag) => true,
+            Value::Tag(_, v) => v.truthy_under(policy),
+            Value::Array(a) => a[..].truthy_under(policy),
+            Value::Map(m) => policy.map(m.len()),
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{Python, Ruby};
+
+    fn tag(tag: u64, value: Value) -> Value {
+        Value::Tag(tag, Box::new(value))
+    }
+
+    #[test]
+    fn truthy() {
+        let values = [
+            Value::Integer(1.into()),
+            Value::Integer((-1).into()),
+            Value::Bytes(vec![0]),
+            Value::Float(f64::NAN),
+            Value::Text("0".into()),
+            Value::Bool(true),
+            Value::Array(vec![Value::Null]),
+            Value::Map(vec![(Value::Null, Value::Null)]),
+            tag(24, Value::Bytes(vec![0])),
+            tag(0, Value::Text("".into())),
+            tag(1, Value::Integer(0.into())),
+        ];
+        for value in &values {
+            assert!(value.truthy(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn falsy() {
+        let values = [
+            Value::Integer(0.into()),
+            Value::Bytes(vec![]),
+            Value::Float(0.0),
+            Value::Text("".into()),
+            Value::Bool(false),
+            Value::Null,
+            Value::Array(vec![]),
+            Value::Map(vec![]),
+            tag(24, Value::Bytes(vec![])),
+        ];
+        for value in &values {
+            assert!(value.falsy(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn policy() {
+        assert!(Value::Map(vec![]).falsy_with::<Python>());
+        assert!(Value::Map(vec![]).truthy_with::<Ruby>());
+        assert!(tag(1, Value::Integer(0.into())).truthy_with::<Python>());
+    }
+}
@@ -0,0 +1,148 @@
+use serde_json::{Map, Number, Value};
+
+use crate::policy::{JavaScript, Policy};
+use crate::Truthy;
+
+impl Truthy for Value {
+    /// Follows JavaScript's rules
+    ///
+    /// `null`, `false`, `0`, `-0`, and `""` are falsy. Arrays and objects are always truthy,
+    /// even if they're empty. Use the [`Python`](crate::policy::Python) policy to make empty
+    /// arrays and objects falsy.
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// use serde_json::json;
+    /// use truthy::policy::Python;
+    ///
+    /// assert!(json!([]).truthy());
+    /// assert!(json!({}).truthy());
+    /// assert!(json!(-0.0).falsy());
+    /// assert!(json!(null).falsy());
+    ///
+    /// assert!(json!([]).falsy_with::<Python>());
+    /// assert!(json!({}).falsy_with::<Python>());
+    /// ```
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.truthy(),
+            Value::String(s) => s.truthy(),
+            Value::Array(_) | Value::Object(_) => true,
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.truthy_under(policy),
+            Value::String(s) => policy.str(s),
+            Value::Array(a) => policy.seq(a.len()),
+            Value::Object(o) => o.truthy_under(policy),
+        }
+    }
+}
+
+impl Truthy for Number {
+    /// `true` if not equal to `0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use serde_json::Number;
+    /// assert!(Number::from(1).truthy());
+    /// assert!(Number::from_f64(-0.0).unwrap().falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        if let Some(n) = self.as_u64() {
+            n.truthy()
+        } else if let Some(n) = self.as_i64() {
+            n.truthy()
+        } else if let Some(n) = self.as_f64() {
+            JavaScript.float(n)
+        } else {
+            true
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        if let Some(n) = self.as_u64() {
+            n.truthy_under(policy)
+        } else if let Some(n) = self.as_i64() {
+            n.truthy_under(policy)
+        } else if let Some(n) = self.as_f64() {
+            policy.float(n)
+        } else {
+            true
+        }
+    }
+}
+
+impl Truthy for Map<String, Value> {
+    /// Always `true`, like JavaScript objects
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use serde_json::Map;
+    /// assert!(Map::new().truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        true
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.map(self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::policy::{JavaScript, Python};
+    use crate::Truthy;
+    use serde_json::json;
+
+    mod javascript {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            for value in &[
+                json!(true),
+                json!(1),
+                json!(-1),
+                json!(0.5),
+                json!("0"),
+                json!("false"),
+                json!([]),
+                json!([0]),
+                json!({}),
+            ] {
+                assert!(value.truthy(), "{}", value);
+                assert!(value.truthy_with::<JavaScript>(), "{}", value);
+            }
+        }
+
+        #[test]
+        fn falsy() {
+            for value in &[json!(null), json!(false), json!(0), json!(0.0), json!(-0.0), json!("")] {
+                assert!(value.falsy(), "{}", value);
+                assert!(value.falsy_with::<JavaScript>(), "{}", value);
+            }
+        }
+    }
+    mod python {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            for value in &[json!(true), json!(1), json!("0"), json!([0]), json!({"a": null})] {
+                assert!(value.truthy_with::<Python>(), "{}", value);
+            }
+        }
+
+        #[test]
+        fn falsy() {
+            for value in &[json!(null), json!(false), json!(0), json!(-0.0), json!(""), json!([]), json!({})] {
+                assert!(value.falsy_with::<Python>(), "{}", value);
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Implementations of `Truthy` for the dynamic values of data formats
+#[cfg(feature = "ciborium")]
+mod cbor;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "rmpv")]
+mod msgpack;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "serde_yaml")]
+mod yaml;
@@ -0,0 +1,113 @@
+use rmpv::Value;
+
+use crate::policy::Policy;
+use crate::Truthy;
+
+/// The extension type for timestamps
+const TIMESTAMP_EXT: i8 = -1;
+
+impl Truthy for Value {
+    /// Follows the rules for the contained value
+    ///
+    /// `nil` is falsy, numbers are truthy if not `0`, and strings, binaries, arrays, and maps
+    /// are truthy if not empty. Extension values are truthy if their data isn't empty, except
+    /// for timestamps, which are always truthy.
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// use rmpv::Value;
+    ///
+    /// assert!(Value::Nil.falsy());
+    /// assert!(Value::from("").falsy());
+    /// assert!(Value::Ext(-1, vec![0; 4]).truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Nil => false,
+            Value::Boolean(b) => *b,
+            Value::Integer(i) => i.as_u64() != Some(0),
+            Value::F32(f) => f.truthy(),
+            Value::F64(f) => f.truthy(),
+            Value::String(s) => s.as_bytes()[..].truthy(),
+            Value::Binary(b) => b[..].truthy(),
+            Value::Array(a) => a[..].truthy(),
+            Value::Map(m) => m[..].truthy(),
+            Value::Ext(TIMESTAMP_EXT, _) => true,
+            Value::Ext(_, data) => data[..].truthy(),
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        match self {
+            Value::Nil => false,
+            Value::Boolean(b) => *b,
+            Value::Integer(i) => {
+                let value = i.as_i64().map(i128::from).or_else(|| i.as_u64().map(i128::from));
+                policy.int(value.unwrap_or_default())
+            }
+            Value::F32(f) => f.truthy_under(policy),
+            Value::F64(f) => f.truthy_under(policy),
+            Value::String(s) => match s.as_str() {
+                Some(s) => policy.str(s),
+                None => policy.seq(s.as_bytes().len()),
+            },
+            Value::Binary(b) => b[..].truthy_under(policy),
+            Value::Array(a) => a[..].truthy_under(policy),
+            Value::Map(m) => policy.map(m.len()),
+            Value::Ext(TIMESTAMP_EXT, _) => true,
+            Value::Ext(_, data) => data[..].truthy_under(policy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{Perl, Ruby};
+
+    #[test]
+    fn truthy() {
+        let values = [
+            Value::Boolean(true),
+            Value::from(1),
+            Value::from(-1),
+            Value::from(u64::MAX),
+            Value::F32(0.5),
+            Value::F64(f64::NAN),
+            Value::from("0"),
+            Value::Binary(vec![0]),
+            Value::Array(vec![Value::Nil]),
+            Value::Map(vec![(Value::Nil, Value::Nil)]),
+            Value::Ext(1, vec![0]),
+            Value::Ext(TIMESTAMP_EXT, vec![0; 4]),
+        ];
+        for value in &values {
+            assert!(value.truthy(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn falsy() {
+        let values = [
+            Value::Nil,
+            Value::Boolean(false),
+            Value::from(0),
+            Value::F32(0.0),
+            Value::F64(-0.0),
+            Value::from(""),
+            Value::Binary(vec![]),
+            Value::Array(vec![]),
+            Value::Map(vec![]),
+            Value::Ext(1, vec![]),
+        ];
+        for value in &values {
+            assert!(value.falsy(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn policy() {
+        assert!(Value::from("0").falsy_with::<Perl>());
+        assert!(Value::from(0).truthy_with::<Ruby>());
+        assert!(Value::Nil.falsy_with::<Ruby>());
+    }
+}
@@ -0,0 +1,88 @@
+use ::toml::{Table, Value};
+
+use crate::policy::Policy;
+use crate::Truthy;
+
+impl Truthy for Value {
+    /// Follows the rules for the contained value
+    ///
+    /// Numbers are truthy if not `0`, and strings, arrays, and tables are truthy if not empty.
+    /// Datetimes are always truthy.
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// let table: toml::Table = "name = '' \n port = 8080 \n hosts = []".parse().unwrap();
+    /// assert!(table["name"].falsy());
+    /// assert!(table["port"].truthy());
+    /// assert!(table["hosts"].falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        match self {
+            Value::String(s) => s.truthy(),
+            Value::Integer(i) => i.truthy(),
+            Value::Float(f) => f.truthy(),
+            Value::Boolean(b) => *b,
+            Value::Datetime(_) => true,
+            Value::Array(a) => a[..].truthy(),
+            Value::Table(t) => t.truthy(),
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        match self {
+            Value::String(s) => policy.str(s),
+            Value::Integer(i) => i.truthy_under(policy),
+            Value::Float(f) => f.truthy_under(policy),
+            Value::Boolean(b) => *b,
+            Value::Datetime(_) => true,
+            Value::Array(a) => a[..].truthy_under(policy),
+            Value::Table(t) => t.truthy_under(policy),
+        }
+    }
+}
+
+impl Truthy for Table {
+    /// `true` if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!(toml::Table::new().falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.map(self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::Ruby;
+
+    fn value(toml: &str) -> Value {
+        let table: Table = format!("value = {}", toml).parse().unwrap();
+        table["value"].clone()
+    }
+
+    #[test]
+    fn truthy() {
+        for toml in &["true", "1", "-1", "0.5", "nan", "'a'", "[0]", "{ a = 0 }", "1979-05-27"] {
+            assert!(value(toml).truthy(), "{}", toml);
+        }
+    }
+
+    #[test]
+    fn falsy() {
+        for toml in &["false", "0", "0.0", "-0.0", "''", "[]", "{}"] {
+            assert!(value(toml).falsy(), "{}", toml);
+        }
+    }
+
+    #[test]
+    fn policy() {
+        assert!(value("0").truthy_with::<Ruby>());
+        assert!(value("[]").truthy_with::<Ruby>());
+        assert!(value("false").falsy_with::<Ruby>());
+    }
+}
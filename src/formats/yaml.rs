@@ -0,0 +1,119 @@
+use serde_yaml::{Mapping, Number, Value};
+
+use crate::policy::Policy;
+use crate::Truthy;
+
+impl Truthy for Value {
+    /// Follows the rules for the contained value
+    ///
+    /// `null` is falsy, numbers are truthy if not `0`, and strings, sequences, and mappings are
+    /// truthy if not empty. Tagged values follow the rules for the value that they tag.
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// let value: serde_yaml::Value = serde_yaml::from_str("[~, 0, '', !Tag 1]").unwrap();
+    /// assert!(value[0].falsy());
+    /// assert!(value[1].falsy());
+    /// assert!(value[2].falsy());
+    /// assert!(value[3].truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.truthy(),
+            Value::String(s) => s.truthy(),
+            Value::Sequence(s) => s[..].truthy(),
+            Value::Mapping(m) => m.truthy(),
+            Value::Tagged(t) => t.value.truthy(),
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.truthy_under(policy),
+            Value::String(s) => policy.str(s),
+            Value::Sequence(s) => s[..].truthy_under(policy),
+            Value::Mapping(m) => m.truthy_under(policy),
+            Value::Tagged(t) => t.value.truthy_under(policy),
+        }
+    }
+}
+
+impl Truthy for Number {
+    /// `true` if not equal to `0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!(serde_yaml::Number::from(1).truthy());
+    /// assert!(serde_yaml::Number::from(0.0).falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        if let Some(n) = self.as_u64() {
+            n.truthy()
+        } else if let Some(n) = self.as_i64() {
+            n.truthy()
+        } else if let Some(n) = self.as_f64() {
+            n.truthy()
+        } else {
+            true
+        }
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        if let Some(n) = self.as_u64() {
+            n.truthy_under(policy)
+        } else if let Some(n) = self.as_i64() {
+            n.truthy_under(policy)
+        } else if let Some(n) = self.as_f64() {
+            n.truthy_under(policy)
+        } else {
+            true
+        }
+    }
+}
+
+impl Truthy for Mapping {
+    /// `true` if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!(serde_yaml::Mapping::new().falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.map(self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::JavaScript;
+
+    fn value(yaml: &str) -> Value {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn truthy() {
+        for yaml in &["true", "1", "-1", "0.5", ".nan", "a", "[0]", "{a: 0}", "!Tag 1", "!Tag [0]"] {
+            assert!(value(yaml).truthy(), "{}", yaml);
+        }
+    }
+
+    #[test]
+    fn falsy() {
+        for yaml in &["~", "false", "0", "0.0", "-0.0", "''", "[]", "{}", "!Tag 0", "!Tag ''"] {
+            assert!(value(yaml).falsy(), "{}", yaml);
+        }
+    }
+
+    #[test]
+    fn policy() {
+        assert!(value(".nan").falsy_with::<JavaScript>());
+        assert!(value("!Tag []").truthy_with::<JavaScript>());
+    }
+}
@@ -0,0 +1,580 @@
+//! Evaluate [JSONLogic](https://jsonlogic.com) rules
+//!
+//! `if`, `?:`, `and`, `or`, `!`, `!!`, `filter`, `all`, `none`, and `some` decide truthiness
+//! with the `Truthy` implementation for [`Value`], under the [`JsonLogic`] policy.
+//!
+//! This differs from [`Value::truthy`](crate::Truthy::truthy), which follows JavaScript, in one
+//! way: an empty array is falsy here. [JSONLogic's rules](https://jsonlogic.com/truthy.html)
+//! make `[]` falsy, and the official test suite checks it, so the default rules can't be used.
+//!
+//! ```
+//! use serde_json::json;
+//! use truthy::{jsonlogic, Truthy};
+//!
+//! assert!(json!([]).truthy());
+//! assert_eq!(jsonlogic::truthy(&json!({"!!": [[]]}), &json!(null)), Ok(false));
+//! ```
+//!
+//! ```
+//! use serde_json::json;
+//! use truthy::jsonlogic;
+//!
+//! let rule = json!({"if": [{"var": "items"}, "has items", "empty"]});
+//! assert_eq!(jsonlogic::apply(&rule, &json!({"items": []})), Ok(json!("empty")));
+//! assert_eq!(jsonlogic::apply(&rule, &json!({"items": [0]})), Ok(json!("has items")));
+//!
+//! let rule = json!({"and": [{">=": [{"var": "age"}, 18]}, {"var": "name"}]});
+//! assert_eq!(jsonlogic::truthy(&rule, &json!({"age": 21, "name": "Ann"})), Ok(true));
+//! assert_eq!(jsonlogic::truthy(&rule, &json!({"age": 21, "name": ""})), Ok(false));
+//! ```
+//!
+//! Values are compared and converted the way JavaScript does, except that arrays and objects
+//! are compared by value instead of by reference.
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+use serde_json::{Map, Number, Value};
+
+use crate::policy::JsonLogic;
+use crate::Truthy;
+
+/// Applies `rule` to `data`.
+///
+/// Values that aren't rules, like `1` or `"a"`, are returned as they are. Arrays have each of
+/// their items applied.
+pub fn apply(rule: &Value, data: &Value) -> Result<Value, JsonLogicError> {
+    match rule {
+        Value::Array(rules) => {
+            rules.iter().map(|rule| apply(rule, data)).collect::<Result<_, _>>().map(Value::Array)
+        }
+        Value::Object(map) if map.len() == 1 => {
+            let (operator, args) = map.iter().next().unwrap();
+            let args = match args {
+                Value::Array(args) => &args[..],
+                arg => std::slice::from_ref(arg),
+            };
+            operation(operator, args, data)
+        }
+        _ => Ok(rule.clone()),
+    }
+}
+
+/// Applies `rule` to `data`, and checks if the result is truthy.
+pub fn truthy(rule: &Value, data: &Value) -> Result<bool, JsonLogicError> {
+    apply(rule, data).map(|value| is_truthy(&value))
+}
+
+/// The error returned when a rule can't be applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonLogicError {
+    /// The rule uses an operator that doesn't exist
+    UnknownOperator(String),
+}
+
+impl fmt::Display for JsonLogicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonLogicError::UnknownOperator(operator) => {
+                write!(f, "unknown operator {:?}", operator)
+            }
+        }
+    }
+}
+
+impl Error for JsonLogicError {}
+
+fn is_truthy(value: &Value) -> bool {
+    value.truthy_with::<JsonLogic>()
+}
+
+/// Operators that decide which of their arguments to apply
+fn operation(operator: &str, args: &[Value], data: &Value) -> Result<Value, JsonLogicError> {
+    match operator {
+        "if" | "?:" => {
+            let mut pairs = args.chunks_exact(2);
+            for pair in &mut pairs {
+                if is_truthy(&apply(&pair[0], data)?) {
+                    return apply(&pair[1], data);
+                }
+            }
+            match pairs.remainder() {
+                [otherwise] => apply(otherwise, data),
+                _ => Ok(Value::Null),
+            }
+        }
+        "and" | "or" => {
+            let stop_at = operator == "or";
+            let mut current = Value::Null;
+            for arg in args {
+                current = apply(arg, data)?;
+                if is_truthy(&current) == stop_at {
+                    break;
+                }
+            }
+            Ok(current)
+        }
+        "filter" => filter(args, data).map(Value::Array),
+        "map" => {
+            let items = scoped_items(args, data)?;
+            let logic = args.get(1).unwrap_or(&Value::Null);
+            items.iter().map(|item| apply(logic, item)).collect::<Result<_, _>>().map(Value::Array)
+        }
+        "reduce" => {
+            let items = scoped_items(args, data)?;
+            let logic = args.get(1).unwrap_or(&Value::Null);
+            let mut accumulator = match args.get(2) {
+                Some(initial) => apply(initial, data)?,
+                None => Value::Null,
+            };
+            for current in items.iter() {
+                let mut scope = Map::new();
+                scope.insert("current".into(), current.clone());
+                scope.insert("accumulator".into(), accumulator);
+                accumulator = apply(logic, &Value::Object(scope))?;
+            }
+            Ok(accumulator)
+        }
+        "all" => {
+            let items = scoped_items(args, data)?;
+            let logic = args.get(1).unwrap_or(&Value::Null);
+            if items.is_empty() {
+                return Ok(Value::Bool(false));
+            }
+            for item in items.iter() {
+                if !is_truthy(&apply(logic, item)?) {
+                    return Ok(Value::Bool(false));
+                }
+            }
+            Ok(Value::Bool(true))
+        }
+        "none" => filter(args, data).map(|items| Value::Bool(items.is_empty())),
+        "some" => filter(args, data).map(|items| Value::Bool(!items.is_empty())),
+        _ => {
+            let args = args.iter().map(|arg| apply(arg, data)).collect::<Result<Vec<_>, _>>()?;
+            function(operator, &args, data)
+        }
+    }
+}
+
+/// Operators that take applied arguments
+fn function(operator: &str, args: &[Value], data: &Value) -> Result<Value, JsonLogicError> {
+    let arg = |i: usize| args.get(i).unwrap_or(&Value::Null);
+    let value = match operator {
+        "var" => var(args.first(), args.get(1), data),
+        "missing" => Value::Array(missing(args, data)),
+        "missing_some" => {
+            let need = to_number(arg(0));
+            let keys = match arg(1) {
+                Value::Array(keys) => &keys[..],
+                _ => &[],
+            };
+            let missing = missing(keys, data);
+            if (keys.len() - missing.len()) as f64 >= need {
+                Value::Array(Vec::new())
+            } else {
+                Value::Array(missing)
+            }
+        }
+        "==" => Value::Bool(loose_eq(arg(0), arg(1))),
+        "!=" => Value::Bool(!loose_eq(arg(0), arg(1))),
+        "===" => Value::Bool(strict_eq(arg(0), arg(1))),
+        "!==" => Value::Bool(!strict_eq(arg(0), arg(1))),
+        ">" => Value::Bool(compare(arg(0), arg(1)) == Some(Ordering::Greater)),
+        ">=" => {
+            let ordering = compare(arg(0), arg(1));
+            Value::Bool(matches!(ordering, Some(Ordering::Greater) | Some(Ordering::Equal)))
+        }
+        "<" | "<=" => {
+            let allowed = |a: &Value, b: &Value| match compare(a, b) {
+                Some(Ordering::Less) => true,
+                Some(Ordering::Equal) => operator == "<=",
+                _ => false,
+            };
+            let between = args.len() > 2;
+            Value::Bool(allowed(arg(0), arg(1)) && (!between || allowed(arg(1), arg(2))))
+        }
+        "!" => Value::Bool(!is_truthy(arg(0))),
+        "!!" => Value::Bool(is_truthy(arg(0))),
+        "in" => Value::Bool(match arg(1) {
+            Value::String(s) => s.contains(&*to_string(arg(0))),
+            Value::Array(items) => items.iter().any(|item| strict_eq(arg(0), item)),
+            _ => false,
+        }),
+        "cat" => Value::String(args.iter().map(to_string).collect()),
+        "substr" => {
+            let chars: Vec<char> = to_string(arg(0)).chars().collect();
+            let len = chars.len() as i64;
+            let start = to_number(arg(1)) as i64;
+            let start = if start < 0 { (len + start).max(0) } else { start.min(len) };
+            let end = match args.get(2) {
+                Some(count) => {
+                    let count = to_number(count) as i64;
+                    if count < 0 {
+                        (len + count).max(start)
+                    } else {
+                        start.saturating_add(count).min(len)
+                    }
+                }
+                None => len,
+            };
+            Value::String(chars[start as usize..end as usize].iter().collect())
+        }
+        "merge" => {
+            let mut merged = Vec::new();
+            for arg in args {
+                match arg {
+                    Value::Array(items) => merged.extend(items.iter().cloned()),
+                    arg => merged.push(arg.clone()),
+                }
+            }
+            Value::Array(merged)
+        }
+        "+" => number(args.iter().map(parse_float).sum()),
+        "*" => number(args.iter().map(parse_float).product()),
+        "-" => match args {
+            [a] => number(-to_number(a)),
+            _ => number(to_number(arg(0)) - to_number(arg(1))),
+        },
+        "/" => number(to_number(arg(0)) / to_number(arg(1))),
+        "%" => number(to_number(arg(0)) % to_number(arg(1))),
+        "min" => number(extreme(args, f64::INFINITY, f64::min)),
+        "max" => number(extreme(args, f64::NEG_INFINITY, f64::max)),
+        _ => return Err(JsonLogicError::UnknownOperator(operator.into())),
+    };
+    Ok(value)
+}
+
+/// `Math.min` and `Math.max`, which are `NaN` if any argument is `NaN`
+fn extreme(args: &[Value], init: f64, f: fn(f64, f64) -> f64) -> f64 {
+    args.iter().map(to_number).fold(init, |a, b| {
+        if a.is_nan() || b.is_nan() {
+            f64::NAN
+        } else {
+            f(a, b)
+        }
+    })
+}
+
+/// Applies the first argument, for operators that iterate over an array
+fn scoped_items(args: &[Value], data: &Value) -> Result<Vec<Value>, JsonLogicError> {
+    match args.first() {
+        Some(items) => match apply(items, data)? {
+            Value::Array(items) => Ok(items),
+            _ => Ok(Vec::new()),
+        },
+        None => Ok(Vec::new()),
+    }
+}
+
+fn filter(args: &[Value], data: &Value) -> Result<Vec<Value>, JsonLogicError> {
+    let logic = args.get(1).unwrap_or(&Value::Null);
+    let mut kept = Vec::new();
+    for item in scoped_items(args, data)? {
+        if is_truthy(&apply(logic, &item)?) {
+            kept.push(item);
+        }
+    }
+    Ok(kept)
+}
+
+fn var(key: Option<&Value>, default: Option<&Value>, data: &Value) -> Value {
+    let key = match key {
+        None | Some(Value::Null) => return data.clone(),
+        Some(key) => to_string(key),
+    };
+    if key.is_empty() {
+        return data.clone();
+    }
+    let mut value = data;
+    for part in key.split('.') {
+        let next = match value {
+            Value::Object(map) => map.get(part),
+            Value::Array(items) => part.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        };
+        value = match next {
+            Some(next) => next,
+            None => return default.cloned().unwrap_or(Value::Null),
+        };
+    }
+    value.clone()
+}
+
+fn missing(args: &[Value], data: &Value) -> Vec<Value> {
+    let keys = match args.first() {
+        Some(Value::Array(keys)) => &keys[..],
+        _ => args,
+    };
+    keys.iter()
+        .filter(|key| match var(Some(key), None, data) {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Converts the result of arithmetic to a `Value`, like `JSON.stringify`
+fn number(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < 9_007_199_254_740_992.0 {
+        Value::Number((n as i64).into())
+    } else {
+        Number::from_f64(n).map_or(Value::Null, Value::Number)
+    }
+}
+
+/// JavaScript's `ToNumber`
+fn to_number(value: &Value) -> f64 {
+    match value {
+        Value::Null => 0.0,
+        Value::Bool(b) => f64::from(u8::from(*b)),
+        Value::Number(n) => n.as_f64().unwrap_or(f64::NAN),
+        Value::String(s) => string_to_number(s),
+        Value::Array(_) | Value::Object(_) => string_to_number(&to_string(value)),
+    }
+}
+
+fn string_to_number(s: &str) -> f64 {
+    let s = s.trim();
+    let (sign, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if s.is_empty() {
+        0.0
+    } else if unsigned == "Infinity" {
+        sign * f64::INFINITY
+    } else if let Some(radix) = radix(s) {
+        match &s[2..] {
+            digits if digits.starts_with('+') => f64::NAN,
+            digits => u64::from_str_radix(digits, radix).map_or(f64::NAN, |n| n as f64),
+        }
+    } else if unsigned.bytes().all(|b| b.is_ascii_digit() || b"eE.+-".contains(&b)) {
+        s.parse().unwrap_or(f64::NAN)
+    } else {
+        f64::NAN
+    }
+}
+
+/// The radix of a `0x`, `0o`, or `0b` number
+fn radix(s: &str) -> Option<u32> {
+    match s.get(..2)? {
+        "0x" | "0X" => Some(16),
+        "0o" | "0O" => Some(8),
+        "0b" | "0B" => Some(2),
+        _ => None,
+    }
+}
+
+/// JavaScript's `parseFloat`
+fn parse_float(value: &Value) -> f64 {
+    if let Value::Number(n) = value {
+        return n.as_f64().unwrap_or(f64::NAN);
+    }
+    let s = to_string(value);
+    let s = s.trim_start();
+    let bytes = s.as_bytes();
+    let mut end = 0;
+    if let Some(b'+') | Some(b'-') = bytes.first() {
+        end += 1;
+    }
+    if s[end..].starts_with("Infinity") {
+        return if s.starts_with('-') { f64::NEG_INFINITY } else { f64::INFINITY };
+    }
+    let digits = |mut i: usize| {
+        while matches!(bytes.get(i), Some(b) if b.is_ascii_digit()) {
+            i += 1;
+        }
+        i
+    };
+    let int_end = digits(end);
+    let mut number_end = int_end;
+    if bytes.get(int_end) == Some(&b'.') {
+        let frac_end = digits(int_end + 1);
+        if frac_end > int_end + 1 || int_end > end {
+            number_end = frac_end;
+        }
+    }
+    if number_end == end {
+        return f64::NAN;
+    }
+    if let Some(b'e') | Some(b'E') = bytes.get(number_end) {
+        let mut exp_start = number_end + 1;
+        if let Some(b'+') | Some(b'-') = bytes.get(exp_start) {
+            exp_start += 1;
+        }
+        let exp_end = digits(exp_start);
+        if exp_end > exp_start {
+            number_end = exp_end;
+        }
+    }
+    s[..number_end].parse().unwrap_or(f64::NAN)
+}
+
+/// JavaScript's `ToString`
+fn to_string(value: &Value) -> Cow<'_, str> {
+    match value {
+        Value::Null => "null".into(),
+        Value::Bool(b) => b.to_string().into(),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(n), _) => n.to_string().into(),
+            (None, Some(n)) => n.to_string().into(),
+            _ => number_to_string(n.as_f64().unwrap_or(f64::NAN)).into(),
+        },
+        Value::String(s) => s.into(),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Null => "".into(),
+                item => to_string(item),
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+            .into(),
+        Value::Object(_) => "[object Object]".into(),
+    }
+}
+
+fn number_to_string(n: f64) -> String {
+    if n.is_nan() {
+        "NaN".into()
+    } else if n.is_infinite() {
+        if n > 0.0 { "Infinity" } else { "-Infinity" }.into()
+    } else if n == 0.0 {
+        "0".into()
+    } else if n.abs() >= 1e21 || n.abs() < 1e-6 {
+        let s = format!("{:e}", n);
+        match s.find("e-") {
+            Some(_) => s,
+            None => s.replacen('e', "e+", 1),
+        }
+    } else {
+        n.to_string()
+    }
+}
+
+/// JavaScript's `==`
+fn loose_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Null, Value::Null) => true,
+        (Value::Null, _) | (_, Value::Null) => false,
+        (Value::Array(_), Value::Array(_)) | (Value::Object(_), Value::Object(_)) => a == b,
+        (Value::String(a), Value::String(b)) => a == b,
+        (Value::Bool(_), _) | (_, Value::Bool(_)) => to_number(a) == to_number(b),
+        (Value::Array(_), _) | (Value::Object(_), _) => loose_eq(&primitive(a), b),
+        (_, Value::Array(_)) | (_, Value::Object(_)) => loose_eq(a, &primitive(b)),
+        _ => to_number(a) == to_number(b),
+    }
+}
+
+/// JavaScript's `===`
+fn strict_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+/// JavaScript's `<` and `>`, which compare strings as strings and everything else as numbers
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (&*primitive(a), &*primitive(b)) {
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (a, b) => to_number(a).partial_cmp(&to_number(b)),
+    }
+}
+
+/// JavaScript's `ToPrimitive`, which converts arrays and objects to strings
+fn primitive(value: &Value) -> Cow<'_, Value> {
+    match value {
+        Value::Array(_) | Value::Object(_) => Cow::Owned(Value::String(to_string(value).into_owned())),
+        value => Cow::Borrowed(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn apply(rule: Value, data: Value) -> Value {
+        super::apply(&rule, &data).unwrap()
+    }
+
+    mod conversions {
+        use super::*;
+
+        #[test]
+        fn to_number() {
+            assert_eq!(super::to_number(&json!(" 12 ")), 12.0);
+            assert_eq!(super::to_number(&json!("")), 0.0);
+            assert_eq!(super::to_number(&json!("0x10")), 16.0);
+            assert_eq!(super::to_number(&json!([5])), 5.0);
+            assert_eq!(super::to_number(&json!(true)), 1.0);
+            assert!(super::to_number(&json!("inf")).is_nan());
+            assert!(super::to_number(&json!("1a")).is_nan());
+        }
+
+        #[test]
+        fn parse_float() {
+            assert_eq!(super::parse_float(&json!("1.5e3px")), 1500.0);
+            assert_eq!(super::parse_float(&json!(" -.5")), -0.5);
+            assert_eq!(super::parse_float(&json!("7.")), 7.0);
+            assert_eq!(super::parse_float(&json!("Infinityx")), f64::INFINITY);
+            assert!(super::parse_float(&json!("")).is_nan());
+            assert!(super::parse_float(&json!(null)).is_nan());
+        }
+
+        #[test]
+        fn to_string() {
+            assert_eq!(super::to_string(&json!(1.5)), "1.5");
+            assert_eq!(super::to_string(&json!(1e21)), "1e+21");
+            assert_eq!(super::to_string(&json!(1e-7)), "1e-7");
+            assert_eq!(super::to_string(&json!([1, null, "a"])), "1,,a");
+            assert_eq!(super::to_string(&json!({})), "[object Object]");
+        }
+    }
+
+    mod equality {
+        use super::*;
+
+        #[test]
+        fn loose() {
+            assert!(loose_eq(&json!(0), &json!("")));
+            assert!(loose_eq(&json!(true), &json!("1")));
+            assert!(loose_eq(&json!([1]), &json!(1)));
+            assert!(!loose_eq(&json!(null), &json!(0)));
+            assert!(!loose_eq(&json!(false), &json!(null)));
+        }
+
+        #[test]
+        fn strict() {
+            assert!(strict_eq(&json!(1), &json!(1.0)));
+            assert!(!strict_eq(&json!(1), &json!(true)));
+        }
+    }
+
+    #[test]
+    fn truthiness() {
+        assert_eq!(apply(json!({"!!": [[]]}), json!(null)), json!(false));
+        assert_eq!(apply(json!({"!!": [{}]}), json!(null)), json!(true));
+        assert_eq!(apply(json!({"!!": "0"}), json!(null)), json!(true));
+        assert_eq!(apply(json!({"!!": {"/": [0, 0]}}), json!(null)), json!(false));
+    }
+
+    #[test]
+    fn unknown_operator() {
+        let error = super::apply(&json!({"nope": [1]}), &json!(null)).unwrap_err();
+        assert_eq!(error, JsonLogicError::UnknownOperator("nope".into()));
+        assert_eq!(error.to_string(), r#"unknown operator "nope""#);
+    }
+
+    #[test]
+    fn short_circuits() {
+        assert_eq!(apply(json!({"or": [1, {"nope": []}]}), json!(null)), json!(1));
+        assert_eq!(apply(json!({"if": [false, {"nope": []}, 2]}), json!(null)), json!(2));
+    }
+}
@@ -8,6 +8,8 @@
 //!
 //! Enable the `and-or` feature to get access to `truthy_and` and `truthy_or`.
 //!
+//! Enable the `derive` feature to get access to `#[derive(Truthy)]`.
+//!
 //! # Behavior
 //! ```
 //! # use truthy::Truthy;
@@ -34,6 +36,74 @@
 //! assert!(empty.falsy());
 //! ```
 //!
+//! # Deriving
+//!
+//! With the `derive` feature, `Truthy` can be derived for structs. By default, a struct is
+//! truthy if all of its fields are truthy. Structs without fields are falsy, like `()`.
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use truthy::Truthy;
+//!
+//! #[derive(Truthy)]
+//! struct Config {
+//!     name: &'static str,
+//!     retries: u8,
+//! }
+//!
+//! #[derive(Truthy)]
+//! #[truthy(any)]
+//! struct Contact {
+//!     email: Option<&'static str>,
+//!     phone: Option<&'static str>,
+//!     #[truthy(skip)]
+//!     verified: bool,
+//! }
+//!
+//! assert!(Config { name: "app", retries: 3 }.truthy());
+//! assert!(Config { name: "app", retries: 0 }.falsy());
+//! assert!(Contact { email: None, phone: Some("555-0100"), verified: false }.truthy());
+//! # }
+//! ```
+//!
+//! The container attribute `#[truthy(...)]` accepts `all` (the default), `any`, or `always`.
+//! Fields accept `#[truthy(skip)]` and `#[truthy(with = path::to::fn)]`, where the function
+//! takes a reference to the field and returns a `bool`.
+//!
+//! Enums can be derived too. Variants with data are truthy according to their fields, like
+//! `Either<L, R>`, and unit variants are truthy unless marked with `#[truthy(false)]`.
+//! `#[truthy(zero_is_falsy)]` on the enum makes the variant with discriminant `0` falsy,
+//! like `0` for numbers.
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use truthy::Truthy;
+//!
+//! #[derive(Truthy)]
+//! #[truthy(zero_is_falsy)]
+//! enum Mode {
+//!     Off,
+//!     Low,
+//!     High,
+//! }
+//!
+//! #[derive(Truthy)]
+//! enum Payload {
+//!     #[truthy(false)]
+//!     Empty,
+//!     Data(Vec<u8>),
+//! }
+//!
+//! assert!(Mode::Off.falsy());
+//! assert!(Mode::Low.truthy());
+//! assert!(Payload::Empty.falsy());
+//! assert!(Payload::Data(vec![]).falsy());
+//! assert!(Payload::Data(vec![1]).truthy());
+//! # }
+//! ```
+//!
 //! # Example Usage
 //!
 //! ```
@@ -45,9 +115,40 @@
 //!     }
 //! }
 //! ```
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::convert::TryFrom;
+use std::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize};
+use std::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use std::num::{Saturating, Wrapping};
+use std::ops::{Range, RangeInclusive};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
 #[cfg(feature = "either")]
 use either::{Either, Left, Right};
 
+#[cfg(feature = "derive")]
+pub use truthy_derive::Truthy;
+
+use policy::Policy;
+
+mod collections;
+mod formats;
+mod net;
+mod strings;
+
+pub mod env;
+pub mod expr;
+#[cfg(feature = "jsonlogic")]
+pub mod jsonlogic;
+pub mod parse;
+pub mod policy;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod trace;
+
 /// Convert to a `bool`.
 pub trait Truthy {
     /// Converts `&self` to a `bool`.
@@ -56,6 +157,33 @@ pub trait Truthy {
     fn falsy(&self) -> bool {
         !self.truthy()
     }
+    /// Converts `&self` to a `bool` using the rules of a [`Policy`].
+    ///
+    /// Defaults to `self.truthy()`. Types that contain other values should pass the policy
+    /// along to them. See [`Truthy::truthy_with`] for a more convenient way to call this.
+    fn truthy_under(&self, _policy: &dyn Policy) -> bool {
+        self.truthy()
+    }
+    /// Converts `&self` to a `bool` using the rules of `P`.
+    ///
+    /// This needs `Self: Sized`, so that `dyn Truthy` can still be used. For `str`, `[T]`, and
+    /// `dyn Truthy`, call [`Truthy::truthy_under`] instead.
+    ///
+    /// ```
+    /// use truthy::Truthy;
+    /// use truthy::policy::Perl;
+    ///
+    /// assert!(String::from("0").truthy());
+    /// assert!(!String::from("0").truthy_with::<Perl>());
+    /// assert!(!"0".truthy_under(&Perl));
+    /// ```
+    fn truthy_with<P: Policy + Default>(&self) -> bool where Self: Sized {
+        self.truthy_under(&P::default())
+    }
+    /// Not truthy using the rules of `P`
+    fn falsy_with<P: Policy + Default>(&self) -> bool where Self: Sized {
+        !self.truthy_with::<P>()
+    }
     #[cfg(feature = "and-or")]
     /// `Left(self)` if `self` is truthy, else `Right(other)`
     ///
@@ -99,29 +227,663 @@ pub trait Truthy {
 /// # let z = 0u8;
 /// assert_eq!(x.truthy() && (y.truthy() || !z.truthy()), truthy!(x && (y || !z)));
 /// ```
+///
+/// The operands can be any expression. Only the `&&`, `||`, and `!` that aren't inside of an
+/// operand are converted, with the same precedence and short-circuiting as in Rust.
+///
+/// ```
+/// # use truthy::truthy;
+/// struct Config { name: &'static str, retries: Vec<u8> }
+/// let config = Config { name: "app", retries: vec![] };
+///
+/// assert!(truthy!(Some(1).unwrap() && 0 + 1));
+/// assert!(truthy!(config.name && !config.retries || config.retries[0]));
+/// assert!(truthy!({ let n = 2; n * 2 } && config.name.len() > 1));
+/// ```
+///
+/// `^` is binary XOR, so `a ^ b` is true if exactly one side is truthy, and a chain like
+/// `a ^ b ^ c` is true if an odd number of operands are truthy. `a => b` (`a` implies `b`) is
+/// true unless `a` is truthy and `b` is falsy. From loosest to tightest, the operators are:
+///
+/// | Operator | Associativity |
+/// |----------|---------------|
+/// | `=>`     | right         |
+/// | `\|\|`   | left          |
+/// | `&&`     | left          |
+/// | `^`      | left          |
+/// | `!`      |               |
+///
+/// Everything else, including comparisons like `count > 3`, is part of an operand, so its
+/// result is converted instead of its operands.
+///
+/// ```
+/// # use truthy::truthy;
+/// let (name, count) = ("app", 5);
+/// assert!(truthy!(name && count > 3));
+/// assert!(truthy!(name ^ count == 5 => false));
+/// assert!(truthy!(count => name ^ ""));
+/// ```
+///
+/// A `!` at the start of an operand negates the truthiness of the whole operand, so it can't be
+/// followed by a binary operator like `==` or `<`, where `!` would mean something else in Rust.
+/// Use parentheses to negate a comparison, or to negate only `a` with Rust's `!`. Expressions
+/// parsed by [`expr`] have the same rule.
+///
+/// ```
+/// # use truthy::truthy;
+/// let (a, b) = (1u8, 2u8);
+/// assert!(truthy!(!(a == b)));
+/// assert!(truthy!((!a) == 254));
+/// ```
+///
+/// ```compile_fail
+/// # use truthy::truthy;
+/// let (a, b) = (1u8, 2u8);
+/// truthy!(!a == b);
+/// ```
 #[macro_export]
 macro_rules! truthy {
-    ( ! $( $tokens:tt )+ ) => {
-        ! $crate::truthy!( $( $tokens )+ )
+    // Splits the tokens into operands in one pass, and then passes them to the macro named by
+    // `{name $($args)*}` as `@emit $($args)* [$($antecedent)*] $consequent`. The operands of `=>`
+    // are lists of the operands of `||`, which are lists of the operands of `&&`, which are lists
+    // of the operands of `^`, and each of those is `([$($not)*] [$($tokens)+])`.
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)+]
+    ) => {
+        $crate::truthy!(@callback $callback
+            [$($implies)*] ($($or)* ($($and)* ($($xor)* ([$($not)*] [$($current)+]))))
+        )
+    };
+    // A `!` at the start of an operand
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] []
+        ! $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)*] [$($and)*] [$($xor)*] [$($not)* !] []
+            $($rest)*
+        )
+    };
+    // An operator without an operand before it
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] []
+        && $($rest:tt)*
+    ) => {
+        $crate::truthy!(@)
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] []
+        || $($rest:tt)*
+    ) => {
+        $crate::truthy!(@)
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] []
+        ^ $($rest:tt)*
+    ) => {
+        $crate::truthy!(@)
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] []
+        => $($rest:tt)*
+    ) => {
+        $crate::truthy!(@)
+    };
+    // An operator after the operand, or after its last token
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)+]
+        && $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)*] [$($and)* ($($xor)* ([$($not)*] [$($current)+]))] [] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)*]
+        $last:tt && $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)*] [$($and)* ($($xor)* ([$($not)*] [$($current)* $last]))]
+            [] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)+]
+        || $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)* ($($and)* ($($xor)* ([$($not)*] [$($current)+])))] [] [] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)*]
+        $last:tt || $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)* ($($and)* ($($xor)* ([$($not)*] [$($current)* $last])))]
+            [] [] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)+]
+        ^ $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)*] [$($and)*] [$($xor)* ([$($not)*] [$($current)+])] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)*]
+        $last:tt ^ $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)*] [$($and)*] [$($xor)* ([$($not)*] [$($current)* $last])] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)+]
+        => $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)* ($($or)* ($($and)* ($($xor)* ([$($not)*] [$($current)+]))))]
+            [] [] [] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)*]
+        $last:tt => $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)* ($($or)* ($($and)* ($($xor)* ([$($not)*] [$($current)* $last]))))]
+            [] [] [] [] []
+            $($rest)*
+        )
+    };
+    (@parse $callback:tt
+        [$($implies:tt)*] [$($or:tt)*] [$($and:tt)*] [$($xor:tt)*] [$($not:tt)*] [$($current:tt)*]
+        $next:tt $($rest:tt)*
+    ) => {
+        $crate::truthy!(@parse $callback
+            [$($implies)*] [$($or)*] [$($and)*] [$($xor)*] [$($not)*] [$($current)* $next]
+            $($rest)*
+        )
+    };
+    (@callback {$name:ident $($args:tt)*} $($parsed:tt)+) => {
+        $crate::$name!(@emit $($args)* $($parsed)+)
+    };
+    // `a => b` is `!a || b`, and the other operators have the same precedence as in Rust
+    (@emit [$($antecedent:tt)*] $consequent:tt) => {
+        $( !$crate::truthy!(@or $antecedent) || )* $crate::truthy!(@or $consequent)
+    };
+    (@or ( $($and:tt)+ )) => {
+        $( $crate::truthy!(@and $and) )||+
+    };
+    (@and ( $($xor:tt)+ )) => {
+        $( $crate::truthy!(@xor $xor) )&&+
+    };
+    (@xor ( $($operand:tt)+ )) => {
+        $( $crate::truthy!(@operand $operand) )^+
+    };
+    (@operand ([$($not:tt)*] [( $($inner:tt)+ )])) => {
+        $($not)* ( $crate::truthy!(@parse {truthy} [] [] [] [] [] [] $($inner)+) )
+    };
+    (@operand ([$($not:tt)*] [$operand:tt])) => {
+        $($not)* ( $operand ).truthy()
+    };
+    (@operand ([] [$($operand:tt)+])) => {
+        ( $($operand)+ ).truthy()
+    };
+    (@operand ([$($not:tt)+] [$first:tt $($rest:tt)+])) => {{
+        $crate::truthy!(@unary [] $($rest)+);
+        $($not)+ ( $first $($rest)+ ).truthy()
+    }};
+    // Rejects binary operators after the first token of a negated operand, skipping generic
+    // arguments like `::<Vec<u8>>`
+    (@unary []) => {};
+    (@unary [] :: < $($rest:tt)*) => { $crate::truthy!(@unary [<] $($rest)*) };
+    (@unary [$($depth:tt)+] < $($rest:tt)*) => { $crate::truthy!(@unary [< $($depth)+] $($rest)*) };
+    (@unary [< $($depth:tt)*] > $($rest:tt)*) => { $crate::truthy!(@unary [$($depth)*] $($rest)*) };
+    (@unary [< < $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::truthy!(@unary [$($depth)*] $($rest)*)
+    };
+    (@unary [$($depth:tt)+] $next:tt $($rest:tt)*) => {
+        $crate::truthy!(@unary [$($depth)+] $($rest)*)
+    };
+    (@unary [] == $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] != $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] < $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] <= $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] > $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] >= $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] + $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] - $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] * $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] / $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] % $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] & $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] | $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] << $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] >> $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] as $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] .. $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] ..= $($rest:tt)*) => { $crate::truthy!(@negated_binary) };
+    (@unary [] $next:tt $($rest:tt)*) => { $crate::truthy!(@unary [] $($rest)*) };
+    (@negated_binary) => {
+        compile_error!("`!` negates the whole operand, so it can't be followed by a binary operator; \
+                        use parentheses, like `!(a == b)` or `(!a) == b`")
+    };
+    (@ $($tokens:tt)*) => {
+        compile_error!("expected an expression before and after each `&&`, `||`, `^`, and `=>`")
+    };
+    ( $($tokens:tt)+ ) => {{
+        use $crate::Truthy as _;
+        $crate::truthy!(@parse {truthy} [] [] [] [] [] [] $($tokens)+)
+    }};
+}
+
+/// [`truthy!`], but also returns a [`Trace`](trace::Trace) of each operand's truthiness.
+///
+/// Operands that weren't evaluated because of short-circuiting are in the trace too, so it is
+/// easy to see which operand decided the result.
+///
+/// ```
+/// # use truthy::truthy_trace;
+/// let (name, count) = ("", 3);
+/// let (result, trace) = truthy_trace!(name && count > 1 || !count);
+/// assert!(!result);
+/// assert_eq!(trace.to_string(), "\
+/// name && count > 1 || !count = false
+///   name: falsy
+///   count > 1: skipped
+///   count: truthy
+/// ");
+/// ```
+///
+/// With the `tracing` feature, `Trace::emit` emits the trace as a `tracing` event.
+#[macro_export]
+macro_rules! truthy_trace {
+    // `truthy!` splits the operands, and these rules use `if` instead of `&&`, `||`, and `=>`, so
+    // that skipped operands can be recorded
+    (@emit $trace:ident eval [] $consequent:tt) => {
+        $crate::truthy_trace!(@or $trace $consequent)
+    };
+    (@emit $trace:ident eval [$antecedent:tt $($rest:tt)*] $consequent:tt) => {
+        if $crate::truthy_trace!(@or $trace $antecedent) {
+            $crate::truthy_trace!(@emit $trace eval [$($rest)*] $consequent)
+        } else {
+            $crate::truthy_trace!(@emit $trace skip [$($rest)*] $consequent);
+            true
+        }
+    };
+    (@emit $trace:ident skip [$($antecedent:tt)*] $consequent:tt) => {
+        $( $crate::truthy_trace!(@skip_or $trace $antecedent); )*
+        $crate::truthy_trace!(@skip_or $trace $consequent)
+    };
+    (@or $trace:ident ( $and:tt )) => {
+        $crate::truthy_trace!(@and $trace $and)
+    };
+    (@or $trace:ident ( $($and:tt)+ )) => {{
+        let mut result = false;
+        $(
+            if result {
+                $crate::truthy_trace!(@skip_and $trace $and);
+            } else {
+                result = $crate::truthy_trace!(@and $trace $and);
+            }
+        )+
+        result
+    }};
+    (@and $trace:ident ( $xor:tt )) => {
+        $crate::truthy_trace!(@xor $trace $xor)
+    };
+    (@and $trace:ident ( $($xor:tt)+ )) => {{
+        let mut result = true;
+        $(
+            if result {
+                result = $crate::truthy_trace!(@xor $trace $xor);
+            } else {
+                $crate::truthy_trace!(@skip_xor $trace $xor);
+            }
+        )+
+        result
+    }};
+    (@xor $trace:ident ( $($operand:tt)+ )) => {
+        $( $crate::truthy_trace!(@operand $trace $operand) )^+
+    };
+    (@operand $trace:ident ([$($not:tt)*] [( $($inner:tt)+ )])) => {
+        $($not)* ( $crate::truthy!(@parse {truthy_trace $trace eval} [] [] [] [] [] [] $($inner)+) )
+    };
+    (@operand $trace:ident ([$($not:tt)*] [$operand:tt])) => {
+        $($not)* $trace.record(stringify!($operand), ( $operand ).truthy())
+    };
+    (@operand $trace:ident ([] [$($operand:tt)+])) => {
+        $trace.record(stringify!($($operand)+), ( $($operand)+ ).truthy())
+    };
+    (@operand $trace:ident ([$($not:tt)+] [$first:tt $($rest:tt)+])) => {{
+        $crate::truthy!(@unary [] $($rest)+);
+        $($not)+ $trace.record(stringify!($first $($rest)+), ( $first $($rest)+ ).truthy())
+    }};
+    // Records each operand as skipped
+    (@skip_or $trace:ident ( $($and:tt)+ )) => {
+        $( $crate::truthy_trace!(@skip_and $trace $and) );+
+    };
+    (@skip_and $trace:ident ( $($xor:tt)+ )) => {
+        $( $crate::truthy_trace!(@skip_xor $trace $xor) );+
+    };
+    (@skip_xor $trace:ident ( $($operand:tt)+ )) => {
+        $( $crate::truthy_trace!(@skip_operand $trace $operand) );+
+    };
+    (@skip_operand $trace:ident ([$($not:tt)*] [( $($inner:tt)+ )])) => {
+        $crate::truthy!(@parse {truthy_trace $trace skip} [] [] [] [] [] [] $($inner)+)
+    };
+    (@skip_operand $trace:ident ([$($not:tt)*] [$($operand:tt)+])) => {
+        $trace.skip(stringify!($($operand)+))
+    };
+    (@ $($tokens:tt)*) => {
+        $crate::truthy!(@)
+    };
+    ( $($tokens:tt)+ ) => {{
+        use $crate::Truthy as _;
+        let mut trace = $crate::trace::Trace::new(stringify!($($tokens)+));
+        let result = $crate::truthy!(@parse {truthy_trace trace eval} [] [] [] [] [] [] $($tokens)+);
+        trace.finish(result)
+    }};
+}
+
+/// The first truthy value, or the last value if none are truthy.
+///
+/// Like `||` in JavaScript, values are only evaluated until a truthy one is found. All of the
+/// values must have the same type. See [`coalesce_into!`] to convert them to a type first.
+///
+/// ```
+/// # use truthy::coalesce;
+/// let nickname = "";
+/// let name = "Ann";
+/// assert_eq!(coalesce!(nickname, name, "anonymous"), "Ann");
+/// assert_eq!(coalesce!(0, 0), 0);
+/// assert_eq!(coalesce!(1, unreachable!()), 1);
+/// ```
+#[macro_export]
+macro_rules! coalesce {
+    ($last:expr $(,)?) => {
+        $last
+    };
+    ($first:expr, $($rest:expr),+ $(,)?) => {{
+        use $crate::Truthy as _;
+        let value = $first;
+        if value.truthy() {
+            value
+        } else {
+            $crate::coalesce!($($rest),+)
+        }
+    }};
+}
+
+/// [`coalesce!`], but each value is converted to `$type` with [`Into`] first.
+///
+/// ```
+/// # use truthy::coalesce_into;
+/// let small = 0u8;
+/// let large = 70_000u32;
+/// assert_eq!(coalesce_into!(u64; small, large), 70_000u64);
+/// ```
+#[macro_export]
+macro_rules! coalesce_into {
+    ($type:ty; $($value:expr),+ $(,)?) => {
+        $crate::coalesce!($( ::core::convert::Into::<$type>::into($value) ),+)
+    };
+}
+
+/// [`coalesce!`] with `||` between the values, like in JavaScript.
+///
+/// `&&` can be used too, and works like [`truthy_and!`]. As in Rust, `&&` binds more tightly
+/// than `||`, and parentheses can be used for grouping.
+///
+/// ```
+/// # use truthy::truthy_or;
+/// let nickname = "";
+/// let name = "Ann";
+/// assert_eq!(truthy_or!(nickname || name || "anonymous"), "Ann");
+/// assert_eq!(truthy_or!(nickname && name || "anonymous"), "anonymous");
+/// ```
+#[macro_export]
+macro_rules! truthy_or {
+    (@split [$( ( $($done:tt)+ ) )*] [$($current:tt)+]) => {
+        $crate::coalesce!($( $crate::truthy_and!($($done)+), )* $crate::truthy_and!($($current)+))
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] || $($rest:tt)*) => {
+        $crate::truthy_or!(@split [$($done)* ($($current)*)] [] $($rest)*)
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::truthy_or!(@split [$($done)*] [$($current)* $next] $($rest)*)
+    };
+    (@ $($tokens:tt)*) => {
+        compile_error!("expected an expression before and after each `&&` and `||`")
+    };
+    ( $($tokens:tt)+ ) => {
+        $crate::truthy_or!(@split [] [] $($tokens)+)
+    };
+}
+
+/// The first falsy value, or the last value if none are falsy.
+///
+/// This works like `&&` in JavaScript, and is the counterpart of [`truthy_or!`]. Values are
+/// only evaluated until a falsy one is found, and must all have the same type.
+///
+/// ```
+/// # use truthy::truthy_and;
+/// let name = "Ann";
+/// let greeting = "Hello";
+/// assert_eq!(truthy_and!(name && greeting), "Hello");
+/// assert_eq!(truthy_and!("" && greeting), "");
+/// assert_eq!(truthy_and!(0 && unreachable!()), 0);
+/// ```
+#[macro_export]
+macro_rules! truthy_and {
+    (@split [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::truthy_and!(@chain $($done)* ($($current)+))
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] && $($rest:tt)*) => {
+        $crate::truthy_and!(@split [$($done)* ($($current)*)] [] $($rest)*)
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::truthy_and!(@split [$($done)*] [$($current)* $next] $($rest)*)
+    };
+    (@chain ( $($last:tt)+ )) => {
+        $crate::truthy_and!(@operand $($last)+)
+    };
+    (@chain ( $($first:tt)+ ) $($rest:tt)+) => {{
+        use $crate::Truthy as _;
+        let value = $crate::truthy_and!(@operand $($first)+);
+        if value.falsy() {
+            value
+        } else {
+            $crate::truthy_and!(@chain $($rest)+)
+        }
+    }};
+    (@operand ( $($inner:tt)+ )) => {
+        $crate::truthy_or!($($inner)+)
+    };
+    (@operand $($operand:tt)+) => {
+        $($operand)+
+    };
+    (@ $($tokens:tt)*) => {
+        compile_error!("expected an expression before and after each `&&` and `||`")
+    };
+    ( $($tokens:tt)+ ) => {
+        $crate::truthy_and!(@split [] [] $($tokens)+)
+    };
+}
+
+/// Runs the first branch with the value if it is truthy, or the `else` branch if not.
+///
+/// The value is only evaluated once, and is moved into the pattern after `|`. Without an `else`
+/// branch, the first branch must return `()`.
+///
+/// ```
+/// # use truthy::if_truthy;
+/// let name = "Ann";
+/// let greeting = if_truthy!(name => |name| format!("Hello, {}", name), else "Hello".to_string());
+/// assert_eq!(greeting, "Hello, Ann");
+///
+/// if_truthy!(Some(0) => |value| unreachable!("{:?} is falsy", value));
+/// ```
+#[macro_export]
+macro_rules! if_truthy {
+    ($value:expr => |$binding:pat| $then:expr, else $else:expr $(,)?) => {{
+        use $crate::Truthy as _;
+        let value = $value;
+        if value.truthy() {
+            let $binding = value;
+            $then
+        } else {
+            $else
+        }
+    }};
+    ($value:expr => |$binding:pat| $then:expr $(,)?) => {
+        $crate::if_truthy!($value => |$binding| $then, else ())
+    };
+}
+
+/// A ternary expression, `condition ? then : else`, where the condition is checked with
+/// [`truthy!`].
+///
+/// The condition can use any of the operators of [`truthy!`]. The first `?` ends the condition
+/// and the next `:` ends the first branch, so a `?` or `:` inside of them must be in
+/// parentheses. Conditions can be chained in the `else` branch.
+///
+/// ```
+/// # use truthy::cond;
+/// let (name, count) = ("Ann", 0);
+/// assert_eq!(cond!(name && count ? "some" : "none"), "none");
+/// assert_eq!(cond!(count ? "many" : name ? "named" : "empty"), "named");
+/// ```
+#[macro_export]
+macro_rules! cond {
+    (@condition [$($condition:tt)+] ? $($rest:tt)+) => {
+        $crate::cond!(@then [$($condition)+] [] $($rest)+)
+    };
+    (@condition [$($condition:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::cond!(@condition [$($condition)* $next] $($rest)*)
+    };
+    (@then [$($condition:tt)+] [$($then:tt)+] : $($rest:tt)+) => {
+        if $crate::truthy!($($condition)+) {
+            $($then)+
+        } else {
+            $crate::cond!(@else [] $($rest)+)
+        }
+    };
+    (@then [$($condition:tt)+] [$($then:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::cond!(@then [$($condition)+] [$($then)* $next] $($rest)*)
+    };
+    // The `else` branch is another condition if it has a `?`
+    (@else [$($else:tt)+]) => {
+        $($else)+
+    };
+    (@else [$($else:tt)*] ? $($rest:tt)*) => {
+        $crate::cond!($($else)* ? $($rest)*)
+    };
+    (@else [$($else:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::cond!(@else [$($else)* $next] $($rest)*)
+    };
+    (@ $($tokens:tt)*) => {
+        compile_error!("expected `condition ? then : else`")
+    };
+    ( $($tokens:tt)+ ) => {
+        $crate::cond!(@condition [] $($tokens)+)
+    };
+}
+
+/// Matches on the truthiness of one or more values.
+///
+/// Each pattern is a tuple with `truthy`, `falsy`, `_`, or `..` for each value, or a single
+/// `truthy`, `falsy`, or `_`. Arms are separated by commas, even if they are blocks, and are
+/// checked for exhaustiveness like any other `match`.
+///
+/// ```
+/// # use truthy::truthy_match;
+/// let (name, count) = ("Ann", 0);
+/// let description = truthy_match!(name, count;
+///     (truthy, truthy) => "named with items",
+///     (truthy, falsy) => "named",
+///     (falsy, ..) => "unnamed",
+/// );
+/// assert_eq!(description, "named");
+///
+/// assert!(truthy_match!(count; falsy => true, truthy => false));
+/// ```
+#[macro_export]
+macro_rules! truthy_match {
+    (@pattern ( $($pattern:tt),+ $(,)? )) => {
+        ( $( $crate::truthy_match!(@value $pattern), )+ )
+    };
+    (@pattern _) => {
+        _
     };
-    ( ( $( $tokens:tt )+ ) ) => {
-        ( $crate::truthy!( $( $tokens )+ ) )
+    (@pattern $pattern:tt) => {
+        ( $crate::truthy_match!(@value $pattern), )
     };
-    ( ( $( $tokens:tt )+ ) && $( $remainder:tt )+ ) => {
-        ( $crate::truthy!( $( $tokens )+ ) ) && $crate::truthy!( $( $remainder )+ )
+    (@value truthy) => {
+        true
     };
-    ( ( $( $tokens:tt )+ ) || $( $remainder:tt )+ ) => {
-        ( $crate::truthy!( $( $tokens )+ ) ) || $crate::truthy!( $( $remainder )+ )
+    (@value falsy) => {
+        false
     };
-    ( $i:ident ) => {
-        $i.truthy()
+    (@value _) => {
+        _
     };
-    ( $i:ident && $($remainder:tt)+ ) => {
-        $i.truthy() && $crate::truthy!( $($remainder)+ )
+    (@value ..) => {
+        ..
     };
-    ( $i:ident || $($remainder:tt)+ ) => {
-        $i.truthy() || $crate::truthy!( $($remainder)+ )
+    (@value $($tokens:tt)*) => {
+        compile_error!("expected `truthy`, `falsy`, `_`, or `..`")
     };
+    ($($value:expr),+ ; $($pattern:tt => $arm:expr),+ $(,)?) => {{
+        use $crate::Truthy as _;
+        match ( $( ($value).truthy(), )+ ) {
+            $( $crate::truthy_match!(@pattern $pattern) => $arm, )+
+        }
+    }};
+}
+
+/// Truthiness that is known at compile time.
+///
+/// `TRUTHINESS` is `Some` if every value of the type has the same truthiness under the default
+/// rules of [`Truthy::truthy`]. Policies can still disagree, like [`policy::JavaScript`], where
+/// empty arrays are truthy.
+///
+/// This is a separate trait from [`Truthy`], so that `dyn Truthy` can still be used.
+///
+/// ```
+/// use truthy::ConstTruthy;
+///
+/// assert_eq!(<[u8; 0]>::TRUTHINESS, Some(false));
+/// assert_eq!(<[u8; 4]>::TRUTHINESS, Some(true));
+/// assert_eq!(u8::TRUTHINESS, None);
+/// ```
+pub trait ConstTruthy: Truthy {
+    /// `Some(truthiness)` if every value has the same truthiness, or `None` if it depends on the
+    /// value
+    const TRUTHINESS: Option<bool>;
+}
+
+/// [`ConstTruthy::TRUTHINESS`] as a `const fn`, for use in static assertions.
+///
+/// ```
+/// use truthy::const_truthiness;
+///
+/// const _: () = assert!(matches!(const_truthiness::<[(); 0]>(), Some(false)));
+/// const _: () = assert!(const_truthiness::<bool>().is_none());
+/// ```
+pub const fn const_truthiness<T: ConstTruthy + ?Sized>() -> Option<bool> {
+    T::TRUTHINESS
 }
 
 macro_rules! impl_truthy_num {
@@ -132,6 +894,49 @@ macro_rules! impl_truthy_num {
                 const FALSY: $type = 0;
                 !self.eq(&FALSY)
             }
+            fn truthy_under(&self, policy: &dyn $crate::policy::Policy) -> bool {
+                policy.int(i128::try_from(*self).unwrap_or(i128::MAX))
+            }
+        }
+
+        impl $crate::ConstTruthy for $type {
+            const TRUTHINESS: Option<bool> = None;
+        }
+    };
+}
+
+macro_rules! impl_truthy_non_zero {
+    ($type:ty) => {
+        impl $crate::Truthy for $type {
+            /// Always `true`, since it can't be `0`
+            fn truthy(&self) -> bool {
+                true
+            }
+            fn truthy_under(&self, policy: &dyn $crate::policy::Policy) -> bool {
+                policy.int(i128::try_from(self.get()).unwrap_or(i128::MAX))
+            }
+        }
+
+        impl $crate::ConstTruthy for $type {
+            const TRUTHINESS: Option<bool> = Some(true);
+        }
+    };
+}
+
+macro_rules! impl_truthy_wrapper {
+    ($wrapper:ident) => {
+        impl<T> $crate::Truthy for $wrapper<T> where T: $crate::Truthy {
+            /// `true` if the wrapped value is "truthy"
+            fn truthy(&self) -> bool {
+                self.0.truthy()
+            }
+            fn truthy_under(&self, policy: &dyn $crate::policy::Policy) -> bool {
+                self.0.truthy_under(policy)
+            }
+        }
+
+        impl<T> $crate::ConstTruthy for $wrapper<T> where T: $crate::ConstTruthy {
+            const TRUTHINESS: Option<bool> = T::TRUTHINESS;
         }
     };
 }
@@ -144,9 +949,27 @@ macro_rules! impl_truthy_tuple {
                 true
             }
         }
+
+        impl<$($G),+> $crate::ConstTruthy for ($($G),+,) {
+            const TRUTHINESS: Option<bool> = Some(true);
+        }
     }
 }
 
+macro_rules! impl_truthy_pointer {
+    ($pointer:ident) => {
+        impl<T> $crate::Truthy for $pointer<T> where T: $crate::Truthy + ?Sized {
+            /// `true` if the value that it points to is "truthy"
+            fn truthy(&self) -> bool {
+                (**self).truthy()
+            }
+            fn truthy_under(&self, policy: &dyn $crate::policy::Policy) -> bool {
+                (**self).truthy_under(policy)
+            }
+        }
+    };
+}
+
 impl_truthy_num!(i8);
 impl_truthy_num!(i16);
 impl_truthy_num!(i32);
@@ -160,6 +983,23 @@ impl_truthy_num!(u64);
 impl_truthy_num!(u128);
 impl_truthy_num!(usize);
 
+impl_truthy_non_zero!(NonZeroI8);
+impl_truthy_non_zero!(NonZeroI16);
+impl_truthy_non_zero!(NonZeroI32);
+impl_truthy_non_zero!(NonZeroI64);
+impl_truthy_non_zero!(NonZeroI128);
+impl_truthy_non_zero!(NonZeroIsize);
+impl_truthy_non_zero!(NonZeroU8);
+impl_truthy_non_zero!(NonZeroU16);
+impl_truthy_non_zero!(NonZeroU32);
+impl_truthy_non_zero!(NonZeroU64);
+impl_truthy_non_zero!(NonZeroU128);
+impl_truthy_non_zero!(NonZeroUsize);
+
+impl_truthy_wrapper!(Wrapping);
+impl_truthy_wrapper!(Saturating);
+impl_truthy_wrapper!(Reverse);
+
 impl_truthy_tuple! {T1}
 impl_truthy_tuple! {T1, T2}
 impl_truthy_tuple! {T1, T2, T3}
@@ -173,6 +1013,10 @@ impl_truthy_tuple! {T1, T2, T3, T4, T5, T6, T7, T8, T9, T10}
 impl_truthy_tuple! {T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11}
 impl_truthy_tuple! {T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12}
 
+impl_truthy_pointer!(Box);
+impl_truthy_pointer!(Rc);
+impl_truthy_pointer!(Arc);
+
 impl Truthy for bool {
     /// Just returns `self`
     ///
@@ -186,30 +1030,112 @@ impl Truthy for bool {
     }
 }
 
-impl Truthy for f32 {
-    /// "truthy" if not `0.0`
+impl ConstTruthy for bool {
+    const TRUTHINESS: Option<bool> = None;
+}
+
+impl Truthy for char {
+    /// "truthy" if not `'\0'`
     ///
     /// ```
     /// # use truthy::Truthy;
-    /// assert!(0.1f32.truthy());
-    /// assert!(!0.0f32.truthy());
+    /// assert!('0'.truthy());
+    /// assert!(!'\0'.truthy());
     /// ```
     fn truthy(&self) -> bool {
-        !self.eq(&0f32)
+        *self != '\0'
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.int(i128::from(u32::from(*self)))
     }
 }
 
-impl Truthy for f64 {
+impl ConstTruthy for char {
+    const TRUTHINESS: Option<bool> = None;
+}
+
+impl Truthy for f32 {
     /// "truthy" if not `0.0`
     ///
     /// ```
     /// # use truthy::Truthy;
-    /// assert!(0.1f64.truthy());
+    /// assert!(0.1f32.truthy());
+    /// assert!(!0.0f32.truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.eq(&0f32)
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.float(f64::from(*self))
+    }
+}
+
+impl ConstTruthy for f32 {
+    const TRUTHINESS: Option<bool> = None;
+}
+
+impl Truthy for f64 {
+    /// "truthy" if not `0.0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!(0.1f64.truthy());
     /// assert!(!0.0f64.truthy());
     /// ```
     fn truthy(&self) -> bool {
         !self.eq(&0f64)
     }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.float(*self)
+    }
+}
+
+impl ConstTruthy for f64 {
+    const TRUTHINESS: Option<bool> = None;
+}
+
+impl Truthy for Duration {
+    /// "truthy" if not zero
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::time::Duration;
+    /// assert!(Duration::from_millis(1).truthy());
+    /// assert!(!Duration::ZERO.truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_zero()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.int(i128::try_from(self.as_nanos()).unwrap_or(i128::MAX))
+    }
+}
+
+impl<T> Truthy for Range<T> where T: PartialOrd {
+    /// "truthy" if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!((0..1).truthy());
+    /// assert!(!(1..1).truthy());
+    /// assert!(!(2..1).truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> Truthy for RangeInclusive<T> where T: PartialOrd {
+    /// "truthy" if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!((1..=1).truthy());
+    /// assert!(!(2..=1).truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
 }
 
 impl Truthy for () {
@@ -224,30 +1150,71 @@ impl Truthy for () {
     }
 }
 
+impl ConstTruthy for () {
+    const TRUTHINESS: Option<bool> = Some(false);
+}
+
 impl Truthy for str {
     /// `true` if not empty
     ///
-    /// This implementation is mainly used to allow this method to be available
-    /// to types that implement `Deref<Target=str>`, such as `String`.
-    ///
     /// ```
     /// # use truthy::Truthy;
-    /// assert!(String::from(" ").truthy());
+    /// assert!(" ".truthy());
+    /// assert!("".falsy());
     /// ```
     fn truthy(&self) -> bool {
         !self.is_empty()
     }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.str(self)
+    }
 }
 
-impl Truthy for &str {
-    /// `true` if not empty
+impl<T> Truthy for &T where T: Truthy + ?Sized {
+    /// `true` if the referenced value is "truthy"
+    ///
+    /// This allows references, like `&str`, to be used where `T: Truthy` is required.
     ///
     /// ```
     /// # use truthy::Truthy;
-    /// assert!(" ".truthy());
+    /// fn is_truthy<T: Truthy>(value: T) -> bool {
+    ///     value.truthy()
+    /// }
+    /// assert!(is_truthy(" "));
+    /// assert!(!is_truthy(&0u8));
     /// ```
     fn truthy(&self) -> bool {
-        !self.is_empty()
+        (**self).truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        (**self).truthy_under(policy)
+    }
+}
+
+impl<T> Truthy for &mut T where T: Truthy + ?Sized {
+    /// `true` if the referenced value is "truthy"
+    fn truthy(&self) -> bool {
+        (**self).truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        (**self).truthy_under(policy)
+    }
+}
+
+impl<T> Truthy for Cow<'_, T> where T: Truthy + ToOwned + ?Sized {
+    /// `true` if the borrowed or owned value is "truthy"
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::borrow::Cow;
+    /// assert!(Cow::Borrowed("a").truthy());
+    /// assert!(Cow::<[u8]>::Owned(Vec::new()).falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        (**self).truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        (**self).truthy_under(policy)
     }
 }
 
@@ -265,6 +1232,13 @@ impl<T> Truthy for Option<T> where T: Truthy {
             false
         }
     }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        if let Some(v) = self {
+            v.truthy_under(policy)
+        } else {
+            false
+        }
+    }
 }
 
 impl<T, E> Truthy for Result<T, E> where T: Truthy {
@@ -282,6 +1256,13 @@ impl<T, E> Truthy for Result<T, E> where T: Truthy {
             false
         }
     }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        if let Ok(v) = self {
+            v.truthy_under(policy)
+        } else {
+            false
+        }
+    }
 }
 
 #[cfg(feature = "either")]
@@ -302,6 +1283,12 @@ impl<L, R> Truthy for Either<L, R> where L: Truthy, R: Truthy {
             Right(r) => r.truthy(),
         }
     }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        match self {
+            Left(l) => l.truthy_under(policy),
+            Right(r) => r.truthy_under(policy),
+        }
+    }
 }
 
 impl<T> Truthy for [T] {
@@ -312,10 +1299,33 @@ impl<T> Truthy for [T] {
     /// assert!([(), (), ()].truthy());
     /// ```
     fn truthy(&self) -> bool {
-        self.len() > 0
+        !self.is_empty()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.seq(self.len())
+    }
+}
+
+impl<T, const N: usize> Truthy for [T; N] {
+    /// `true` if `N` is not `0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!([0u8; 4].truthy());
+    /// assert!([0u8; 0].falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        N != 0
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.seq(N)
     }
 }
 
+impl<T, const N: usize> ConstTruthy for [T; N] {
+    const TRUTHINESS: Option<bool> = Some(N != 0);
+}
+
 #[cfg(test)]
 mod tests {
     use super::Truthy;
@@ -342,6 +1352,62 @@ mod tests {
             assert!(!"".truthy());
         }
     }
+    mod pointers {
+        use super::Truthy;
+        use crate::policy::Perl;
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        fn is_truthy<T: Truthy>(value: T) -> bool {
+            value.truthy()
+        }
+
+        #[test]
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        fn references() {
+            assert!(is_truthy(&1u8));
+            assert!(!is_truthy(&mut 0u8));
+            assert!(is_truthy(&&Some(true)));
+            assert!(!is_truthy(&[0u8; 0][..]));
+        }
+
+        #[test]
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        fn str_references() {
+            assert!(is_truthy("a"));
+            assert!(!is_truthy(""));
+            assert!("a".truthy());
+            assert!(Some("a").truthy());
+            assert!(Some("").falsy());
+            assert!(!is_truthy(&mut String::new().as_mut_str()));
+        }
+
+        #[test]
+        fn smart_pointers() {
+            assert!(is_truthy(Box::new(1u8)));
+            assert!(!is_truthy(Box::<str>::from("")));
+            assert!(is_truthy(Rc::<[u8]>::from(vec![1])));
+            assert!(!is_truthy(Arc::new(None::<u8>)));
+            assert!(is_truthy(Arc::new(Box::new("a"))));
+        }
+
+        #[test]
+        fn cows() {
+            assert!(is_truthy(Cow::Borrowed("a")));
+            assert!(!is_truthy(Cow::<str>::Owned(String::new())));
+            assert!(!is_truthy(Cow::<[u8]>::Borrowed(&[])));
+        }
+
+        #[test]
+        fn forwards_policy() {
+            assert!(!"0".truthy_under(&Perl));
+            assert!((&"0").falsy_with::<Perl>());
+            assert!(Box::<str>::from("0").falsy_with::<Perl>());
+            assert!(Cow::Borrowed("0").falsy_with::<Perl>());
+            assert!(Rc::new(Some("00")).truthy_with::<Perl>());
+        }
+    }
     mod ints {
         use super::Truthy;
 
@@ -502,6 +1568,219 @@ mod tests {
             }
         }
     }
+    mod chars {
+        use super::Truthy;
+        use crate::policy::Ruby;
+
+        #[test]
+        fn truthy() {
+            assert!('0'.truthy());
+            assert!(' '.truthy());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!('\0'.falsy());
+        }
+
+        #[test]
+        fn policy() {
+            assert!('\0'.truthy_with::<Ruby>());
+        }
+    }
+    mod non_zeros {
+        use super::Truthy;
+        use std::num::*;
+
+        mod i8 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroI8::new(1).unwrap().truthy())
+            }
+        }
+        mod i16 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroI16::new(1).unwrap().truthy())
+            }
+        }
+        mod i32 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroI32::new(1).unwrap().truthy())
+            }
+        }
+        mod i64 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroI64::new(1).unwrap().truthy())
+            }
+        }
+        mod i128 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroI128::new(1).unwrap().truthy())
+            }
+        }
+        mod isize {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroIsize::new(1).unwrap().truthy())
+            }
+        }
+        mod u8 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroU8::new(1).unwrap().truthy())
+            }
+        }
+        mod u16 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroU16::new(1).unwrap().truthy())
+            }
+        }
+        mod u32 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroU32::new(1).unwrap().truthy())
+            }
+        }
+        mod u64 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroU64::new(1).unwrap().truthy())
+            }
+        }
+        mod u128 {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroU128::new(1).unwrap().truthy())
+            }
+        }
+        mod usize {
+            use super::*;
+
+            #[test]
+            fn truthy() {
+                assert!(NonZeroUsize::new(1).unwrap().truthy())
+            }
+        }
+
+        #[test]
+        fn const_truthiness() {
+            assert_eq!(crate::const_truthiness::<NonZeroU32>(), Some(true));
+        }
+    }
+    mod wrappings {
+        use super::Truthy;
+        use std::num::Wrapping;
+
+        #[test]
+        fn truthy() {
+            assert!((Wrapping(u8::MAX) + Wrapping(2)).truthy())
+        }
+
+        #[test]
+        fn falsy() {
+            assert!((Wrapping(u8::MAX) + Wrapping(1)).falsy())
+        }
+    }
+    mod saturatings {
+        use super::Truthy;
+        use std::num::Saturating;
+
+        #[test]
+        fn truthy() {
+            assert!((Saturating(0u8) + Saturating(1)).truthy())
+        }
+
+        #[test]
+        fn falsy() {
+            assert!((Saturating(1u8) - Saturating(2)).falsy())
+        }
+    }
+    mod reverses {
+        use super::Truthy;
+        use crate::policy::Perl;
+        use std::cmp::Reverse;
+
+        #[test]
+        fn truthy() {
+            assert!(Reverse("a").truthy())
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(Reverse(0.0).falsy())
+        }
+
+        #[test]
+        fn policy() {
+            assert!(Reverse("0").falsy_with::<Perl>());
+            assert_eq!(crate::const_truthiness::<Reverse<[u8; 0]>>(), Some(false));
+        }
+    }
+    mod durations {
+        use super::Truthy;
+        use crate::policy::Ruby;
+        use std::time::Duration;
+
+        #[test]
+        fn truthy() {
+            assert!(Duration::from_nanos(1).truthy())
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(Duration::from_secs(0).falsy())
+        }
+
+        #[test]
+        fn policy() {
+            assert!(Duration::ZERO.truthy_with::<Ruby>())
+        }
+    }
+    mod ranges {
+        use super::Truthy;
+
+        #[test]
+        fn truthy() {
+            assert!((0..3).truthy());
+            assert!((-1.0..=-1.0).truthy());
+            assert!(('a'..'b').truthy());
+        }
+
+        #[test]
+        fn falsy() {
+            let (start, end) = (3, 2);
+            assert!((start..start).falsy());
+            assert!((start..=end).falsy());
+            assert!((0.0..f64::NAN).falsy());
+        }
+    }
     mod floats {
         use super::Truthy;
 
@@ -536,6 +1815,7 @@ mod tests {
         use super::Truthy;
 
         #[test]
+        #[allow(clippy::useless_vec)]
         fn truthy() {
             assert!(vec!["I'm here!"].truthy())
         }
@@ -549,6 +1829,12 @@ mod tests {
     }
     mod arrays {
         use super::Truthy;
+        use crate::policy::JavaScript;
+        use crate::ConstTruthy;
+
+        fn is_truthy<T: Truthy>(value: T) -> bool {
+            value.truthy()
+        }
 
         #[test]
         fn truthy() {
@@ -561,6 +1847,26 @@ mod tests {
 
             assert!(empty_array.falsy())
         }
+
+        #[test]
+        fn generic() {
+            assert!(is_truthy([0u8; 4]));
+            assert!(!is_truthy([0u8; 0]));
+        }
+
+        #[test]
+        fn policy() {
+            assert!([0u8; 0].truthy_with::<JavaScript>());
+        }
+
+        #[test]
+        fn const_truthiness() {
+            const EMPTY: Option<bool> = crate::const_truthiness::<[String; 0]>();
+            const NOT_EMPTY: Option<bool> = crate::const_truthiness::<[(); 1]>();
+            assert_eq!(EMPTY, Some(false));
+            assert_eq!(NOT_EMPTY, Some(true));
+            assert_eq!(<[[u8; 0]; 2]>::TRUTHINESS, Some(true));
+        }
     }
     mod options {
         use super::Truthy;
@@ -618,13 +1924,17 @@ mod tests {
         fn falsy() {
             assert!(!().truthy())
         }
+
+        #[test]
+        fn const_truthiness() {
+            assert_eq!(crate::const_truthiness::<()>(), Some(false));
+            assert_eq!(crate::const_truthiness::<(u8, bool)>(), Some(true));
+        }
     }
 }
 
 #[cfg(test)]
 mod macro_tests {
-    use super::{Truthy, truthy};
-
     /// A few different uses of `truthy!`
     #[test]
     fn truthy_macro() {
@@ -637,4 +1947,415 @@ mod macro_tests {
         assert!(truthy!((x && !y) || z));
         assert!(truthy!(((!(!x) && !!!y) || z)));
     }
+
+    mod expressions {
+        struct Config {
+            name: &'static str,
+            retries: Vec<u8>,
+            parent: Option<&'static str>,
+        }
+
+        impl Config {
+            fn name(&self) -> &str {
+                self.name
+            }
+
+            fn is_named(&self) -> bool {
+                truthy!(self.name && self.name())
+            }
+        }
+
+        fn config() -> Config {
+            Config { name: "app", retries: vec![0], parent: None }
+        }
+
+        #[test]
+        fn methods_and_fields() {
+            let config = config();
+            assert!(truthy!(config.name && config.retries));
+            assert!(truthy!(config.name() || config.parent));
+            assert!(truthy!(config.is_named() && !config.parent));
+            assert!(!truthy!(config.retries[0] || config.parent.is_some()));
+        }
+
+        #[test]
+        #[allow(clippy::unnecessary_literal_unwrap, clippy::identity_op)]
+        fn literals_and_calls() {
+            assert!(truthy!(Some(1).unwrap() && 0 + 1));
+            assert!(truthy!("a" && 1.5 && true));
+            assert!(!truthy!(0 || "" || None::<u8>));
+            assert!(truthy!(i32::max(0, 1) && u8::MAX));
+        }
+
+        #[test]
+        #[allow(clippy::let_and_return)]
+        fn blocks_and_comparisons() {
+            let x = 2;
+            assert!(truthy!({ let y = x - 2; y } || x > 1 && x == 2));
+            assert!(truthy!(if x > 1 { "a" } else { "" }));
+            assert!(truthy!(!(x == 3)));
+            assert!(truthy!((!x) == -3));
+        }
+
+        #[test]
+        #[allow(clippy::nonminimal_bool)]
+        fn negated_operands() {
+            let (name, items) = ("", vec![0u8]);
+            assert!(truthy!(!name.trim() && !items.is_empty()));
+            assert!(truthy!(!vec![0u8; 0] && !"1".parse::<u8>().is_err()));
+            assert!(truthy!(!!items.len()));
+        }
+
+        #[test]
+        fn precedence() {
+            let (t, f) = (1u8, 0u8);
+            assert!(truthy!(t || f && f));
+            assert!(!truthy!((t || f) && f));
+            assert!(truthy!(f && f || t));
+            assert!(truthy!(!f && !(f || f)));
+        }
+
+        #[test]
+        fn short_circuits() {
+            let mut calls = 0;
+            let mut call = |value: u8| {
+                calls += 1;
+                value
+            };
+            assert!(truthy!(call(1) || call(1)));
+            assert!(!truthy!(call(0) && call(1)));
+            assert_eq!(calls, 2);
+        }
+
+        #[test]
+        fn long_chains() {
+            struct Counter { n: u8 }
+            let (t, c) = (1u8, Counter { n: 3 });
+            assert!(truthy!(
+                t && t && t && t && t && t && t && t && t && t && t && t && t && t && t &&
+                t && t && t && t && t && t && t && t && t && t && t && t && t && t && t &&
+                t && t && t && t && t && t && t && t && t && t && t && t && t && t && t &&
+                t && t && t
+            ));
+            assert!(truthy!(c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2));
+            assert!(truthy_trace!(c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2 && c.n > 2).0);
+        }
+    }
+
+    mod extended {
+        #[test]
+        fn xor() {
+            let (t, f) = (1u8, "");
+            assert!(truthy!(t ^ f));
+            assert!(!truthy!(t ^ t));
+            assert!(!truthy!(f ^ f));
+            assert!(truthy!(t ^ t ^ t));
+        }
+
+        #[test]
+        fn implies() {
+            let (t, f) = ("a", 0.0);
+            assert!(truthy!(t => t));
+            assert!(!truthy!(t => f));
+            assert!(truthy!(f => t));
+            assert!(truthy!(f => f));
+        }
+
+        #[test]
+        fn implies_is_right_associative() {
+            let f = false;
+            // `(f => f) => f` would be false
+            assert!(truthy!(f => f => f));
+            assert!(!truthy!((f => f) => f));
+        }
+
+        #[test]
+        fn precedence() {
+            let (t, f) = (true, false);
+            // `=>` is looser than `||`
+            assert!(truthy!(f || f => f));
+            // `||` is looser than `&&`, which is looser than `^`
+            assert!(truthy!(t || t && f));
+            assert!(!truthy!(t ^ t && t));
+            assert!(truthy!(t && t ^ f));
+            // `!` is tighter than `^`
+            assert!(truthy!(!f ^ f));
+        }
+
+        #[test]
+        fn comparisons() {
+            let (name, count) = ("app", 5u8);
+            assert!(truthy!(name && count > 3));
+            assert!(!truthy!(name && count >= 6 || count == 0));
+            assert!(truthy!(count != 5 ^ name.len() == 3));
+            assert!(truthy!(!(count < 3) => name));
+        }
+
+        #[test]
+        fn short_circuits() {
+            let mut calls = 0;
+            let mut call = |value: u8| {
+                calls += 1;
+                value
+            };
+            assert!(truthy!(call(0) => call(0)));
+            assert!(!truthy!(call(1) ^ call(1)));
+            // Only `=>` short-circuits
+            assert_eq!(calls, 3);
+        }
+    }
+    mod coalesce {
+        #[test]
+        fn first_truthy() {
+            assert_eq!(coalesce!("", "a", "b"), "a");
+            assert_eq!(coalesce!(None, Some(0), Some(1)), Some(1));
+            assert_eq!(coalesce!(0u8, 2, 3,), 2);
+        }
+
+        #[test]
+        fn last_if_none_are_truthy() {
+            assert_eq!(coalesce!("", ""), "");
+            assert_eq!(coalesce!(0.0), 0.0);
+        }
+
+        #[test]
+        fn lazy() {
+            let mut calls = 0;
+            let mut call = |value: &'static str| {
+                calls += 1;
+                value
+            };
+            assert_eq!(coalesce!(call(""), call("a"), call("b")), "a");
+            assert_eq!(calls, 2);
+        }
+
+        #[test]
+        fn into() {
+            assert_eq!(coalesce_into!(Option<&str>; "", Some("fallback")), Some("fallback"));
+            assert_eq!(coalesce_into!(Option<&str>; "name", Some("fallback")), Some("name"));
+            assert_eq!(coalesce_into!(i64; 0u8, -1i32, 2u32), -1);
+            assert_eq!(coalesce_into!(f64; 0u8, 0.0f32), 0.0);
+        }
+    }
+
+    mod truthy_or {
+        #[test]
+        fn first_truthy() {
+            let (empty, name) = ("", "Ann");
+            assert_eq!(truthy_or!(empty || name), "Ann");
+            assert_eq!(truthy_or!(empty || "" || empty), "");
+            assert_eq!(truthy_or!(name.len() - 3 || 7), 7);
+        }
+
+        #[test]
+        fn precedence() {
+            assert_eq!(truthy_or!(0 || 1 && 2), 2);
+            assert_eq!(truthy_or!(1 && 0 || 3), 3);
+            assert_eq!(truthy_or!((0 || 1) && (4 || 5)), 4);
+        }
+
+        #[test]
+        fn lazy() {
+            let mut calls = 0;
+            let mut call = |value: u8| {
+                calls += 1;
+                value
+            };
+            assert_eq!(truthy_or!(call(0) || call(1) || call(2)), 1);
+            assert_eq!(calls, 2);
+        }
+    }
+
+    mod truthy_and {
+        #[test]
+        fn first_falsy() {
+            assert_eq!(truthy_and!(1 && 0 && 2), 0);
+            assert_eq!(truthy_and!("a" && "" && "b"), "");
+        }
+
+        #[test]
+        fn last_if_none_are_falsy() {
+            assert_eq!(truthy_and!(1 && 2 && 3), 3);
+            assert_eq!(truthy_and!(Some(1)), Some(1));
+        }
+
+        #[test]
+        fn lazy() {
+            let mut calls = 0;
+            let mut call = |value: u8| {
+                calls += 1;
+                value
+            };
+            assert_eq!(truthy_and!(call(1) && call(0) && call(2)), 0);
+            assert_eq!(calls, 2);
+        }
+    }
+
+    mod truthy_trace {
+        use crate::trace::Trace;
+
+        fn operands(trace: &Trace) -> Vec<(&str, Option<bool>)> {
+            trace.operands().iter().map(|operand| (operand.source(), operand.truthy())).collect()
+        }
+
+        #[test]
+        fn same_result_as_truthy() {
+            let (a, b, c) = (1u8, "", Some(0));
+            assert_eq!(truthy_trace!(a && (b || !c)).0, truthy!(a && (b || !c)));
+            assert_eq!(truthy_trace!(!a || b ^ c => a).0, truthy!(!a || b ^ c => a));
+            assert_eq!(truthy_trace!(a ^ b ^ a && c).0, truthy!(a ^ b ^ a && c));
+            assert_eq!(truthy_trace!(b => c => a).0, truthy!(b => c => a));
+        }
+
+        #[test]
+        fn records_operands() {
+            let (a, b) = (1u8, "");
+            let (result, trace) = truthy_trace!(a && !b ^ (a.pow(2) > 3));
+            assert!(result);
+            assert_eq!(trace.expression(), "a && !b ^ (a.pow(2) > 3)");
+            assert_eq!(operands(&trace), [("a", Some(true)), ("b", Some(false)), ("a.pow(2) > 3", Some(false))]);
+        }
+
+        #[test]
+        fn records_skipped_operands() {
+            let (a, b) = (0u8, "b");
+            let (result, trace) = truthy_trace!(a && (b || !a) && b || a);
+            assert!(!result);
+            let expected = [("a", Some(false)), ("b", None), ("a", None), ("b", None), ("a", Some(false))];
+            assert_eq!(operands(&trace), expected);
+            assert!(trace.operands()[1].is_skipped());
+
+            let (result, trace) = truthy_trace!(a => b ^ (a || b));
+            assert!(result);
+            assert_eq!(operands(&trace), [("a", Some(false)), ("b", None), ("a", None), ("b", None)]);
+        }
+
+        #[test]
+        fn evaluates_like_truthy() {
+            let mut calls = 0;
+            let mut call = |value: u8| {
+                calls += 1;
+                value
+            };
+            let (result, trace) = truthy_trace!(call(1) || call(2));
+            assert!(result);
+            assert_eq!(operands(&trace), [("call(1)", Some(true)), ("call(2)", None)]);
+            assert_eq!(calls, 1);
+        }
+
+        #[test]
+        fn hygiene() {
+            let trace = 0u8;
+            let (result, recorded) = truthy_trace!(trace || !trace);
+            assert!(result);
+            assert_eq!(operands(&recorded), [("trace", Some(false)), ("trace", Some(false))]);
+        }
+    }
+
+    mod if_truthy {
+        #[test]
+        fn binds_truthy_value() {
+            assert_eq!(if_truthy!(Some(2) => |value| value.map(|n| n * 2), else None), Some(4));
+            assert_eq!(if_truthy!(Some(0) => |value| value.map(|n| n * 2), else None), None);
+        }
+
+        #[test]
+        fn destructures() {
+            assert_eq!(if_truthy!((1, "a") => |(n, s)| s.repeat(n), else String::new()), "a");
+        }
+
+        #[test]
+        fn without_else() {
+            let mut seen = Vec::new();
+            for value in &[0, 3, 0, 5] {
+                if_truthy!(*value => |value| seen.push(value));
+            }
+            assert_eq!(seen, [3, 5]);
+        }
+
+        #[test]
+        fn evaluates_once() {
+            let mut calls = 0;
+            let mut call = || {
+                calls += 1;
+                7u8
+            };
+            assert_eq!(if_truthy!(call() => |value| value, else 0), 7);
+            assert_eq!(calls, 1);
+        }
+    }
+
+    mod cond {
+        #[test]
+        fn ternary() {
+            assert_eq!(cond!(1 ? "yes" : "no"), "yes");
+            assert_eq!(cond!("" ? "yes" : "no"), "no");
+        }
+
+        #[test]
+        fn operators() {
+            let (a, b) = (Some(1), "");
+            assert_eq!(cond!(a && !b ? 1 : 2), 1);
+            assert_eq!(cond!(a ^ b => false ? 1 : 2), 2);
+            assert_eq!(cond!(a.unwrap() > 0 ? 1 : 2), 1);
+        }
+
+        #[test]
+        fn paths_in_branches() {
+            assert_eq!(cond!(1 ? u8::MAX : std::primitive::u8::MIN), 255);
+            assert_eq!(cond!(0 ? core::primitive::u8::MAX : u8::MIN), 0);
+        }
+
+        #[test]
+        fn chained() {
+            let grade = |score: u8| cond!(score >= 90 ? 'A' : score >= 80 ? 'B' : 'C');
+            assert_eq!(grade(95), 'A');
+            assert_eq!(grade(85), 'B');
+            assert_eq!(grade(10), 'C');
+        }
+
+        #[test]
+        fn lazy() {
+            assert_eq!(cond!(0 ? unreachable!() : 1), 1);
+        }
+    }
+
+    mod truthy_match {
+        #[test]
+        fn single() {
+            assert_eq!(truthy_match!(""; truthy => 1, falsy => 0), 0);
+            assert_eq!(truthy_match!(Some(1); (truthy) => 1, _ => 0), 1);
+        }
+
+        #[test]
+        fn several() {
+            let describe = |a: u8, b: &str| {
+                truthy_match!(a, b;
+                    (truthy, truthy) => "both",
+                    (truthy, falsy) => "first",
+                    (falsy, truthy) => "second",
+                    (falsy, falsy) => "neither",
+                )
+            };
+            assert_eq!(describe(1, "b"), "both");
+            assert_eq!(describe(1, ""), "first");
+            assert_eq!(describe(0, "b"), "second");
+            assert_eq!(describe(0, ""), "neither");
+        }
+
+        #[test]
+        fn wildcards() {
+            assert_eq!(truthy_match!(0, 1, 2; (_, truthy, ..) => 1, _ => 0), 1);
+            assert_eq!(truthy_match!(0, 1, 2; (.., falsy) => 1, _ => 0), 0);
+        }
+
+        #[test]
+        fn blocks() {
+            let value = truthy_match!(1; truthy => {
+                let doubled = 2;
+                doubled * 2
+            }, falsy => 0);
+            assert_eq!(value, 4);
+        }
+    }
 }
@@ -0,0 +1,134 @@
+//! Implementations of `Truthy` for the network addresses in `std`
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::Truthy;
+
+impl Truthy for Ipv4Addr {
+    /// "truthy" if not the unspecified address, `0.0.0.0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::net::Ipv4Addr;
+    /// assert!(Ipv4Addr::LOCALHOST.truthy());
+    /// assert!(!Ipv4Addr::UNSPECIFIED.truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_unspecified()
+    }
+}
+
+impl Truthy for Ipv6Addr {
+    /// "truthy" if not the unspecified address, `::`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::net::Ipv6Addr;
+    /// assert!(Ipv6Addr::LOCALHOST.truthy());
+    /// assert!(!Ipv6Addr::UNSPECIFIED.truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_unspecified()
+    }
+}
+
+impl Truthy for IpAddr {
+    /// "truthy" if not an unspecified address, `0.0.0.0` or `::`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::net::IpAddr;
+    /// assert!("127.0.0.1".parse::<IpAddr>().unwrap().truthy());
+    /// assert!(!"::".parse::<IpAddr>().unwrap().truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_unspecified()
+    }
+}
+
+impl Truthy for SocketAddrV4 {
+    /// "truthy" if the address is "truthy" and the port is not `0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::net::{Ipv4Addr, SocketAddrV4};
+    /// assert!(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080).truthy());
+    /// assert!(!SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0).truthy());
+    /// assert!(!SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 8080).truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        self.ip().truthy() && self.port() != 0
+    }
+}
+
+impl Truthy for SocketAddrV6 {
+    /// "truthy" if the address is "truthy" and the port is not `0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::net::{Ipv6Addr, SocketAddrV6};
+    /// assert!(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0).truthy());
+    /// assert!(!SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0).truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        self.ip().truthy() && self.port() != 0
+    }
+}
+
+impl Truthy for SocketAddr {
+    /// "truthy" if the address is "truthy" and the port is not `0`
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::net::SocketAddr;
+    /// assert!("127.0.0.1:8080".parse::<SocketAddr>().unwrap().truthy());
+    /// assert!(!"0.0.0.0:8080".parse::<SocketAddr>().unwrap().truthy());
+    /// assert!(!"[::1]:0".parse::<SocketAddr>().unwrap().truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        self.ip().truthy() && self.port() != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_truthy<T: Truthy>(value: T) -> bool {
+        value.truthy()
+    }
+
+    mod ip_addrs {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(Ipv4Addr::new(192, 168, 0, 1)));
+            assert!(is_truthy(Ipv6Addr::LOCALHOST));
+            assert!(is_truthy(IpAddr::V4(Ipv4Addr::BROADCAST)));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(Ipv4Addr::UNSPECIFIED));
+            assert!(!is_truthy(Ipv6Addr::UNSPECIFIED));
+            assert!(!is_truthy(IpAddr::V6(Ipv6Addr::UNSPECIFIED)));
+        }
+    }
+    mod socket_addrs {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80)));
+            assert!(is_truthy(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0, 0)));
+            assert!(is_truthy(SocketAddr::from(([10, 0, 0, 1], 443))));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 80)));
+            assert!(!is_truthy(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)));
+            assert!(!is_truthy(SocketAddr::from(([0, 0, 0, 0], 0))));
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! Parse a `bool` from a string
+//!
+//! Unlike `impl Truthy for str`, which only checks if a string is empty, this module
+//! understands words like `"false"` and `"off"`.
+//!
+//! ```
+//! use truthy::parse::{parse_bool, parse_bool_lenient};
+//!
+//! assert_eq!(parse_bool(" Yes "), Ok(true));
+//! assert_eq!(parse_bool("off"), Ok(false));
+//! assert!(parse_bool("maybe").is_err());
+//!
+//! assert!(!parse_bool_lenient("FALSE"));
+//! assert!(parse_bool_lenient("maybe"));
+//! ```
+//!
+//! Other programs spell booleans differently. A [`Profile`] parses values the same way as one
+//! of those programs.
+//!
+//! ```
+//! use truthy::parse::{parse_bool_as, Profile};
+//!
+//! assert_eq!(parse_bool_as(Profile::Git, ""), Ok(false));
+//! assert_eq!(parse_bool_as(Profile::Systemd, "t"), Ok(true));
+//! assert_eq!(parse_bool_as(Profile::Yaml11, "Off"), Ok(false));
+//! assert!(parse_bool_as(Profile::Yaml11, "oFF").is_err());
+//! ```
+//!
+//! Enable the `locale` feature for words in other languages, like `"oui"` and `"nein"`.
+use std::error::Error;
+use std::fmt;
+
+use super::Truthy;
+
+#[cfg(feature = "locale")]
+pub mod locale;
+
+/// Words that [`parse_bool`] reads as `true`.
+pub const TRUTHY_WORDS: &[&str] = &["true", "yes", "on", "y", "1", "enabled"];
+
+/// Words that [`parse_bool`] reads as `false`.
+pub const FALSY_WORDS: &[&str] = &["false", "no", "off", "n", "0", "disabled"];
+
+/// Parses a `bool`, rejecting unknown words.
+///
+/// Recognizes [`TRUTHY_WORDS`] and [`FALSY_WORDS`], ignoring ASCII case and surrounding
+/// whitespace.
+///
+/// ```
+/// # use truthy::parse::parse_bool;
+/// assert_eq!(parse_bool("Enabled"), Ok(true));
+/// assert_eq!(parse_bool("0"), Ok(false));
+///
+/// let err = parse_bool("").unwrap_err();
+/// assert_eq!(err.input(), "");
+/// ```
+pub fn parse_bool(s: &str) -> Result<bool, ParseTruthyError> {
+    parse_bool_as(Profile::Common, s)
+}
+
+/// Parses a `bool`, falling back to `impl Truthy for str` for unknown words.
+///
+/// ```
+/// # use truthy::parse::parse_bool_lenient;
+/// assert!(!parse_bool_lenient("no"));
+/// assert!(parse_bool_lenient("maybe"));
+/// assert!(!parse_bool_lenient(""));
+/// ```
+pub fn parse_bool_lenient(s: &str) -> bool {
+    parse_bool(s).unwrap_or_else(|_| s.truthy())
+}
+
+/// A program's spelling of booleans.
+///
+/// Apart from [`Profile::Common`], profiles don't ignore surrounding whitespace, because the
+/// programs that they copy strip it before parsing the value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Profile {
+    /// This crate's own rules, used by [`parse_bool`]
+    Common,
+    /// `git config --type=bool`
+    ///
+    /// Words are case-insensitive, an empty value is `false`, and other values are read as
+    /// integers, which are `true` if not `0`. Integers may use a `0x` or `0` prefix for hex or
+    /// octal, and a `k`, `m`, or `g` suffix.
+    Git,
+    /// systemd's `parse_boolean`, used for unit files and environment variables
+    ///
+    /// Words are case-insensitive.
+    Systemd,
+    /// YAML 1.1's `bool` type
+    ///
+    /// Words must be all lowercase, capitalized, or all uppercase.
+    Yaml11,
+    /// INI files, as read by Python's `configparser`
+    ///
+    /// Words are case-insensitive.
+    Ini,
+}
+
+impl Profile {
+    /// Words that this profile reads as `true`
+    pub fn truthy_words(self) -> &'static [&'static str] {
+        match self {
+            Profile::Common => TRUTHY_WORDS,
+            Profile::Git => &["true", "yes", "on", "1"],
+            Profile::Systemd => &["1", "yes", "y", "true", "t", "on"],
+            Profile::Yaml11 => &["y", "yes", "true", "on"],
+            Profile::Ini => &["1", "yes", "true", "on"],
+        }
+    }
+
+    /// Words that this profile reads as `false`
+    pub fn falsy_words(self) -> &'static [&'static str] {
+        match self {
+            Profile::Common => FALSY_WORDS,
+            Profile::Git => &["false", "no", "off", "0", ""],
+            Profile::Systemd => &["0", "no", "n", "false", "f", "off"],
+            Profile::Yaml11 => &["n", "no", "false", "off"],
+            Profile::Ini => &["0", "no", "false", "off"],
+        }
+    }
+}
+
+/// Parses a `bool` the same way as the program that `profile` copies.
+///
+/// ```
+/// # use truthy::parse::{parse_bool_as, Profile};
+/// assert_eq!(parse_bool_as(Profile::Git, "2k"), Ok(true));
+/// assert_eq!(parse_bool_as(Profile::Ini, "ON"), Ok(true));
+/// assert!(parse_bool_as(Profile::Systemd, " yes").is_err());
+/// ```
+pub fn parse_bool_as(profile: Profile, s: &str) -> Result<bool, ParseTruthyError> {
+    let truthy = profile.truthy_words();
+    let falsy = profile.falsy_words();
+    let parsed = match profile {
+        Profile::Common => parse_words(s.trim(), truthy, falsy, str::eq_ignore_ascii_case),
+        Profile::Git => parse_words(s, truthy, falsy, str::eq_ignore_ascii_case)
+            .or_else(|| parse_git_int(s).map(|n| n != 0)),
+        Profile::Systemd | Profile::Ini => {
+            parse_words(s, truthy, falsy, str::eq_ignore_ascii_case)
+        }
+        Profile::Yaml11 => parse_words(s, truthy, falsy, |s, word| {
+            let mut chars = word.chars();
+            let capitalized = chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str());
+            s == word
+                || s == word.to_ascii_uppercase()
+                || Some(s) == capitalized.as_deref()
+        }),
+    };
+    parsed.ok_or_else(|| ParseTruthyError::new(s, truthy, falsy))
+}
+
+/// Parses an integer like `git_parse_signed`, which uses `strtoimax` with base `0` and allows
+/// a unit suffix.
+fn parse_git_int(s: &str) -> Option<i64> {
+    let s = s.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '\x0b');
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (radix, s) = if s.len() > 2 && (s.starts_with("0x") || s.starts_with("0X")) {
+        (16, &s[2..])
+    } else if s.len() > 1 && s.starts_with('0') {
+        (8, &s[1..])
+    } else {
+        (10, s)
+    };
+    let end = s.find(|c: char| !c.is_digit(radix)).unwrap_or(s.len());
+    let (digits, unit) = s.split_at(end);
+    if digits.is_empty() && radix != 8 {
+        return None;
+    }
+    let factor = match unit {
+        "" => 1,
+        "k" | "K" => 1 << 10,
+        "m" | "M" => 1 << 20,
+        "g" | "G" => 1 << 30,
+        _ => return None,
+    };
+    let value = if digits.is_empty() {
+        0
+    } else {
+        i64::from_str_radix(digits, radix).ok()?
+    };
+    let value = if negative { -value } else { value };
+    value.checked_mul(factor)
+}
+
+/// Finds `s` in `truthy` or `falsy`, using `eq` to compare words.
+pub(crate) fn parse_words<W: AsRef<str>>(
+    s: &str,
+    truthy: &[W],
+    falsy: &[W],
+    eq: impl Fn(&str, &str) -> bool,
+) -> Option<bool> {
+    if truthy.iter().any(|word| eq(s, word.as_ref())) {
+        Some(true)
+    } else if falsy.iter().any(|word| eq(s, word.as_ref())) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// The error returned when a string can't be parsed as a `bool`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseTruthyError {
+    input: String,
+    expected: Vec<String>,
+}
+
+impl ParseTruthyError {
+    pub(crate) fn new<W: AsRef<str>>(input: &str, truthy: &[W], falsy: &[W]) -> Self {
+        ParseTruthyError {
+            input: input.to_owned(),
+            expected: truthy
+                .iter()
+                .chain(falsy)
+                .map(|word| word.as_ref().to_owned())
+                .collect(),
+        }
+    }
+
+    /// The string that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The words that would have been accepted
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseTruthyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.expected.is_empty() {
+            return write!(f, "no words are accepted, found {:?}", self.input);
+        }
+        write!(f, "expected one of ")?;
+        for (i, word) in self.expected.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", word)?;
+        }
+        write!(f, ", found {:?}", self.input)
+    }
+}
+
+impl Error for ParseTruthyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod strict {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            for s in &["true", "yes", "on", "y", "1", "enabled", "TRUE", "Yes", " on\n"] {
+                assert_eq!(parse_bool(s), Ok(true), "{:?}", s);
+            }
+        }
+
+        #[test]
+        fn falsy() {
+            for s in &["false", "no", "off", "n", "0", "disabled", "FALSE", "No", "\toff "] {
+                assert_eq!(parse_bool(s), Ok(false), "{:?}", s);
+            }
+        }
+
+        #[test]
+        fn unknown() {
+            for s in &["", " ", "maybe", "2", "yess", "t"] {
+                let err = parse_bool(s).unwrap_err();
+                assert_eq!(err.input(), *s);
+                assert_eq!(err.expected().len(), TRUTHY_WORDS.len() + FALSY_WORDS.len());
+            }
+        }
+
+        #[test]
+        fn profile() {
+            assert_eq!(parse_bool("1"), parse_bool_as(Profile::Common, "1"));
+        }
+
+        #[test]
+        fn error_message() {
+            let err = parse_bool("maybe").unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                r#"expected one of "true", "yes", "on", "y", "1", "enabled", "false", "no", "off", "n", "0", "disabled", found "maybe""#,
+            );
+        }
+    }
+    mod lenient {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(parse_bool_lenient("on"));
+            assert!(parse_bool_lenient("maybe"));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!parse_bool_lenient("off"));
+            assert!(!parse_bool_lenient(" false "));
+            assert!(!parse_bool_lenient(""));
+        }
+    }
+    mod profiles {
+        use super::*;
+
+        /// Checks that each input in `table` parses to the expected output.
+        fn conforms(profile: Profile, table: &[(&str, Option<bool>)]) {
+            for (input, expected) in table {
+                assert_eq!(
+                    parse_bool_as(profile, input).ok(),
+                    *expected,
+                    "{:?} with {:?}",
+                    input,
+                    profile,
+                );
+            }
+        }
+
+        #[test]
+        fn git() {
+            conforms(Profile::Git, &[
+                ("true", Some(true)),
+                ("YES", Some(true)),
+                ("On", Some(true)),
+                ("1", Some(true)),
+                ("false", Some(false)),
+                ("No", Some(false)),
+                ("OFF", Some(false)),
+                ("0", Some(false)),
+                ("", Some(false)),
+                ("-1", Some(true)),
+                ("+2", Some(true)),
+                ("0x0", Some(false)),
+                ("0x10", Some(true)),
+                ("010", Some(true)),
+                ("00", Some(false)),
+                ("1k", Some(true)),
+                ("0g", Some(false)),
+                (" 1", Some(true)),
+                ("y", None),
+                ("t", None),
+                ("1 ", None),
+                ("1x", None),
+                ("0x", None),
+                ("09", None),
+                ("99999999999999999999", None),
+                ("8g", Some(true)),
+                ("9999999999g", None),
+            ]);
+        }
+
+        #[test]
+        fn systemd() {
+            conforms(Profile::Systemd, &[
+                ("1", Some(true)),
+                ("yes", Some(true)),
+                ("Y", Some(true)),
+                ("TRUE", Some(true)),
+                ("t", Some(true)),
+                ("on", Some(true)),
+                ("0", Some(false)),
+                ("NO", Some(false)),
+                ("n", Some(false)),
+                ("False", Some(false)),
+                ("F", Some(false)),
+                ("off", Some(false)),
+                ("", None),
+                (" yes", None),
+                ("2", None),
+                ("enabled", None),
+            ]);
+        }
+
+        #[test]
+        fn yaml11() {
+            conforms(Profile::Yaml11, &[
+                ("y", Some(true)),
+                ("Y", Some(true)),
+                ("yes", Some(true)),
+                ("Yes", Some(true)),
+                ("YES", Some(true)),
+                ("true", Some(true)),
+                ("True", Some(true)),
+                ("TRUE", Some(true)),
+                ("on", Some(true)),
+                ("On", Some(true)),
+                ("ON", Some(true)),
+                ("n", Some(false)),
+                ("N", Some(false)),
+                ("no", Some(false)),
+                ("No", Some(false)),
+                ("NO", Some(false)),
+                ("false", Some(false)),
+                ("False", Some(false)),
+                ("FALSE", Some(false)),
+                ("off", Some(false)),
+                ("Off", Some(false)),
+                ("OFF", Some(false)),
+                ("yEs", None),
+                ("tRUE", None),
+                ("oFF", None),
+                ("1", None),
+                ("0", None),
+                ("", None),
+            ]);
+        }
+
+        #[test]
+        fn ini() {
+            conforms(Profile::Ini, &[
+                ("1", Some(true)),
+                ("yes", Some(true)),
+                ("True", Some(true)),
+                ("oN", Some(true)),
+                ("0", Some(false)),
+                ("NO", Some(false)),
+                ("false", Some(false)),
+                ("Off", Some(false)),
+                ("y", None),
+                ("n", None),
+                ("", None),
+                (" yes", None),
+            ]);
+        }
+    }
+}
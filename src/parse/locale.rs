@@ -0,0 +1,370 @@
+//! Boolean words in other languages
+//!
+//! Requires the `locale` feature. Words are matched after Unicode normalization, full case
+//! folding, and removing diacritics, so `"SÍ"`, `"sí"`, and `"si"` are all the same word, and so
+//! are `"STRASSE"` and `"straße"`. Diacritics are only removed from Latin, Greek, and Cyrillic
+//! letters, since in other scripts they can make a different word, like `"ば"` and `"は"` in
+//! Japanese.
+//!
+//! The tables are only used by [`parse_bool_in`], [`WordTable::parse`], and [`Locales::parse`].
+//! [`parse_bool`](super::parse_bool), the [`env`](crate::env) functions, and the `serde` helpers
+//! always use the English words.
+//!
+//! ```
+//! use truthy::parse::locale::{parse_bool_in, Locales, WordTable};
+//!
+//! assert_eq!(parse_bool_in("fr", "Oui"), Ok(true));
+//! assert_eq!(parse_bool_in("de-AT", "nein"), Ok(false));
+//! assert_eq!(parse_bool_in("es", "SÍ"), Ok(true));
+//! assert_eq!(parse_bool_in("ja", "はい"), Ok(true));
+//!
+//! let mut locales = Locales::new();
+//! locales.insert("x-pirate", WordTable::new(&["aye"], &["nay"]));
+//! assert_eq!(locales.parse("x-pirate", "AYE"), Ok(true));
+//! ```
+use caseless::Caseless;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use super::ParseTruthyError;
+
+/// The tag used when no table matches the requested tag.
+pub const FALLBACK_TAG: &str = "en";
+
+/// Built-in tables, as `(tag, truthy words, falsy words)`.
+const BUILTIN: &[(&str, &[&str], &[&str])] = &[
+    ("en", super::TRUTHY_WORDS, super::FALSY_WORDS),
+    ("de", &["ja", "wahr", "j"], &["nein", "falsch", "n"]),
+    ("es", &["sí", "verdadero", "s"], &["no", "falso", "n"]),
+    ("fr", &["oui", "vrai", "o"], &["non", "faux", "n"]),
+    ("it", &["sì", "vero", "s"], &["no", "falso", "n"]),
+    ("ja", &["はい", "真"], &["いいえ", "偽"]),
+    ("nl", &["ja", "waar", "j"], &["nee", "onwaar", "n"]),
+    ("pt", &["sim", "verdadeiro", "s"], &["não", "falso", "n"]),
+    ("ru", &["да", "истина", "д"], &["нет", "ложь", "н"]),
+    ("sv", &["ja", "sant", "j"], &["nej", "falskt", "n"]),
+    ("zh", &["是", "真", "对"], &["否", "假", "不"]),
+];
+
+/// Parses a `bool` using the built-in table for the BCP-47 `tag`.
+///
+/// If there is no table for `tag`, subtags are removed from the end until one matches, so
+/// `"pt-BR"` uses the table for `"pt"`. Tags that still don't match use [`FALLBACK_TAG`].
+///
+/// ```
+/// # use truthy::parse::locale::parse_bool_in;
+/// assert_eq!(parse_bool_in("pt-BR", "Não"), Ok(false));
+/// assert_eq!(parse_bool_in("tlh", "yes"), Ok(true));
+/// assert!(parse_bool_in("fr", "yes").is_err());
+/// ```
+pub fn parse_bool_in(tag: &str, s: &str) -> Result<bool, ParseTruthyError> {
+    let table = WordTable::for_tag(tag)
+        .or_else(|| WordTable::for_tag(FALLBACK_TAG))
+        .expect("the fallback table should exist");
+    table.parse(s)
+}
+
+/// Normalizes `s` for comparison.
+fn fold(s: &str) -> String {
+    let mut folded = String::new();
+    let mut strip_marks = false;
+    for c in s.trim().nfkd() {
+        if is_combining_mark(c) {
+            if !strip_marks {
+                folded.push(c);
+            }
+            continue;
+        }
+        strip_marks = has_diacritics(c);
+        folded.extend(std::iter::once(c).default_case_fold());
+    }
+    folded
+}
+
+/// `true` for Latin, Greek, and Cyrillic letters, where combining marks are diacritics.
+fn has_diacritics(c: char) -> bool {
+    c.is_alphabetic() && matches!(
+        c,
+        '\u{0041}'..='\u{02AF}'
+            | '\u{0370}'..='\u{052F}'
+            | '\u{1C80}'..='\u{1C8F}'
+            | '\u{1E00}'..='\u{1FFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{2DE0}'..='\u{2DFF}'
+            | '\u{A640}'..='\u{A69F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{AB30}'..='\u{AB6F}'
+    )
+}
+
+/// Normalizes a BCP-47 tag, so that `"pt_br"` and `"pt-BR"` are equal.
+fn fold_tag(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+/// Each shorter form of `tag`, starting with `tag` itself.
+fn tag_fallbacks(tag: &str) -> impl Iterator<Item = &str> {
+    let mut next = Some(tag);
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = current.rfind('-').map(|i| &current[..i]);
+        Some(current)
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Word {
+    text: String,
+    folded: String,
+}
+
+impl AsRef<str> for Word {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Word {
+    fn new(text: &str) -> Self {
+        Word {
+            text: text.to_owned(),
+            folded: fold(text),
+        }
+    }
+}
+
+/// Words that are read as `true` and `false`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordTable {
+    truthy: Vec<Word>,
+    falsy: Vec<Word>,
+}
+
+impl WordTable {
+    /// Creates a table from lists of words.
+    pub fn new<S: AsRef<str>>(truthy: &[S], falsy: &[S]) -> Self {
+        WordTable {
+            truthy: truthy.iter().map(|word| Word::new(word.as_ref())).collect(),
+            falsy: falsy.iter().map(|word| Word::new(word.as_ref())).collect(),
+        }
+    }
+
+    /// The built-in table for the BCP-47 `tag`.
+    ///
+    /// Subtags are removed from the end of `tag` until a table matches.
+    pub fn for_tag(tag: &str) -> Option<Self> {
+        let tag = fold_tag(tag);
+        let table = tag_fallbacks(&tag).find_map(|tag| {
+            BUILTIN
+                .iter()
+                .find(|(builtin, _, _)| *builtin == tag)
+                .map(|(_, truthy, falsy)| WordTable::new(truthy, falsy))
+        });
+        table
+    }
+
+    /// Adds the words of `other` to this table.
+    pub fn extend(&mut self, other: &WordTable) {
+        self.truthy.extend(other.truthy.iter().cloned());
+        self.falsy.extend(other.falsy.iter().cloned());
+    }
+
+    /// Parses a `bool`, rejecting words that aren't in this table.
+    pub fn parse(&self, s: &str) -> Result<bool, ParseTruthyError> {
+        let folded = fold(s);
+        let matches = |words: &[Word]| words.iter().any(|word| word.folded == folded);
+        if matches(&self.truthy) {
+            Ok(true)
+        } else if matches(&self.falsy) {
+            Ok(false)
+        } else {
+            Err(ParseTruthyError::new(s, &self.truthy, &self.falsy))
+        }
+    }
+}
+
+/// A set of word tables, selected by BCP-47 tag.
+///
+/// Starts with the built-in tables, and can be extended with custom tables.
+#[derive(Clone, Debug)]
+pub struct Locales {
+    tables: Vec<(String, WordTable)>,
+}
+
+impl Locales {
+    /// Creates a set containing the built-in tables.
+    pub fn new() -> Self {
+        Locales {
+            tables: BUILTIN
+                .iter()
+                .map(|(tag, truthy, falsy)| (tag.to_string(), WordTable::new(truthy, falsy)))
+                .collect(),
+        }
+    }
+
+    /// Creates a set without any tables.
+    pub fn empty() -> Self {
+        Locales { tables: Vec::new() }
+    }
+
+    /// Adds a table for `tag`, replacing any table that already has that tag.
+    pub fn insert(&mut self, tag: &str, table: WordTable) {
+        let tag = fold_tag(tag);
+        match self.tables.iter_mut().find(|(existing, _)| *existing == tag) {
+            Some((_, existing)) => *existing = table,
+            None => self.tables.push((tag, table)),
+        }
+    }
+
+    /// The table for `tag`, removing subtags from the end until one matches.
+    pub fn get(&self, tag: &str) -> Option<&WordTable> {
+        let tag = fold_tag(tag);
+        let table = tag_fallbacks(&tag).find_map(|tag| {
+            self.tables
+                .iter()
+                .find(|(existing, _)| existing == tag)
+                .map(|(_, table)| table)
+        });
+        table
+    }
+
+    /// Parses a `bool` using the table for `tag`, or [`FALLBACK_TAG`] if none match.
+    ///
+    /// If neither table exists, every string is rejected.
+    pub fn parse(&self, tag: &str, s: &str) -> Result<bool, ParseTruthyError> {
+        match self.get(tag).or_else(|| self.get(FALLBACK_TAG)) {
+            Some(table) => table.parse(s),
+            None => Err(ParseTruthyError::new::<&str>(s, &[], &[])),
+        }
+    }
+}
+
+impl Default for Locales {
+    fn default() -> Self {
+        Locales::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod builtin {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            let cases = [
+                ("fr", "oui"),
+                ("fr-CA", "Vrai"),
+                ("de", "JA"),
+                ("es", "sí"),
+                ("es", "SÍ"),
+                ("es", "si"),
+                ("it", "Sì"),
+                ("pt_BR", "sim"),
+                ("ja", "はい"),
+                ("zh-Hans-CN", "是"),
+                ("ru", "Да"),
+                ("en-US", "Yes"),
+            ];
+            for (tag, s) in &cases {
+                assert_eq!(parse_bool_in(tag, s), Ok(true), "{:?} in {:?}", s, tag);
+            }
+        }
+
+        #[test]
+        fn falsy() {
+            let cases = [
+                ("fr", "non"),
+                ("de-CH", "Nein"),
+                ("es", "NO"),
+                ("pt", "não"),
+                ("pt", "NAO"),
+                ("ja", "いいえ"),
+                ("ru", "НЕТ"),
+                ("sv", "nej"),
+                ("nl", "nee"),
+            ];
+            for (tag, s) in &cases {
+                assert_eq!(parse_bool_in(tag, s), Ok(false), "{:?} in {:?}", s, tag);
+            }
+        }
+
+        #[test]
+        fn unknown() {
+            assert!(parse_bool_in("de", "oui").is_err());
+            let err = parse_bool_in("fr", "peut-être").unwrap_err();
+            assert_eq!(err.input(), "peut-être");
+            assert_eq!(err.expected(), ["oui", "vrai", "o", "non", "faux", "n"]);
+        }
+
+        #[test]
+        fn fallback() {
+            assert_eq!(parse_bool_in("tlh", "yes"), Ok(true));
+            assert!(WordTable::for_tag("tlh").is_none());
+        }
+
+        #[test]
+        fn normalized() {
+            // "e" followed by a combining acute accent
+            let decomposed = "verdadero\u{301}";
+            assert_eq!(parse_bool_in("es", decomposed), Ok(true));
+        }
+
+        #[test]
+        fn case_folded() {
+            let table = WordTable::new(&["straße", "σωστός"], &[]);
+            assert_eq!(table.parse("STRASSE"), Ok(true));
+            assert_eq!(table.parse("STRAẞE"), Ok(true));
+            assert_eq!(table.parse("ΣΩΣΤΟΣ"), Ok(true));
+        }
+
+        #[test]
+        fn meaningful_marks() {
+            let table = WordTable::new(&["ば"], &["は"]);
+            assert_eq!(table.parse("ば"), Ok(true));
+            assert_eq!(table.parse("は"), Ok(false));
+            // "は" followed by a combining voiced sound mark
+            assert_eq!(table.parse("は\u{3099}"), Ok(true));
+            // Marks after punctuation aren't diacritics
+            let table = WordTable::new(&["+"], &[]);
+            assert!(table.parse("+\u{301}").is_err());
+        }
+    }
+    mod custom {
+        use super::*;
+
+        #[test]
+        fn insert() {
+            let mut locales = Locales::new();
+            locales.insert("x-pirate", WordTable::new(&["aye"], &["nay"]));
+            assert_eq!(locales.parse("x-pirate", "Aye"), Ok(true));
+            assert_eq!(locales.parse("x-pirate", "NAY"), Ok(false));
+        }
+
+        #[test]
+        fn replace() {
+            let mut locales = Locales::new();
+            locales.insert("FR", WordTable::new(&["ouais"], &["nan"]));
+            assert_eq!(locales.parse("fr", "ouais"), Ok(true));
+            assert!(locales.parse("fr", "oui").is_err());
+        }
+
+        #[test]
+        fn extend() {
+            let mut table = WordTable::for_tag("de").unwrap();
+            table.extend(&WordTable::new(&["jawohl"], &[]));
+            assert_eq!(table.parse("Jawohl"), Ok(true));
+            assert_eq!(table.parse("ja"), Ok(true));
+        }
+
+        #[test]
+        fn empty() {
+            let locales = Locales::empty();
+            assert!(locales.get("en").is_none());
+            let err = locales.parse("en", "yes").unwrap_err();
+            assert_eq!(err.to_string(), r#"no words are accepted, found "yes""#);
+        }
+    }
+}
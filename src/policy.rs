@@ -0,0 +1,305 @@
+//! Truthiness rules borrowed from other languages
+//!
+//! A [`Policy`] decides the truthiness of the primitive values that this crate implements
+//! [`Truthy`](crate::Truthy) for. Containers, like `Option` and `[T]`, pass the policy along to
+//! the values that they contain.
+//!
+//! ```
+//! use truthy::Truthy;
+//! use truthy::policy::{JavaScript, Perl, Ruby};
+//!
+//! assert!("0".truthy());
+//! assert!(!"0".truthy_under(&Perl));
+//! assert!(0u8.truthy_with::<Ruby>());
+//! assert!(Some(f64::NAN).falsy_with::<JavaScript>());
+//! ```
+//!
+//! | Value        | Default | `Python` | `JavaScript` | `Ruby`/`Lua` | `Perl` | `Php`  | `JsonLogic` |
+//! |--------------|---------|----------|--------------|--------------|--------|--------|-------------|
+//! | `0`, `0.0`   | falsy   | falsy    | falsy        | truthy       | falsy  | falsy  | falsy       |
+//! | `NaN`        | truthy  | truthy   | falsy        | truthy       | truthy | truthy | falsy       |
+//! | `""`         | falsy   | falsy    | falsy        | truthy       | falsy  | falsy  | falsy       |
+//! | `"0"`        | truthy  | truthy   | truthy       | truthy       | falsy  | falsy  | truthy      |
+//! | empty `[T]`  | falsy   | falsy    | truthy       | truthy       | falsy  | falsy  | falsy       |
+//! | empty maps   | falsy   | falsy    | truthy       | truthy       | falsy  | falsy  | truthy      |
+//! | `None`, `()` | falsy   | falsy    | falsy        | falsy        | falsy  | falsy  | falsy       |
+
+/// Rules for the truthiness of primitive values.
+///
+/// Each method defaults to the behavior of [`Truthy::truthy`](crate::Truthy::truthy), so a
+/// policy only needs to override the rules that are different.
+pub trait Policy {
+    /// Truthiness of an integer
+    ///
+    /// Values of `u128` that don't fit in an `i128` are passed as `i128::MAX`.
+    fn int(&self, value: i128) -> bool {
+        value != 0
+    }
+    /// Truthiness of a floating point number
+    fn float(&self, value: f64) -> bool {
+        value != 0.0
+    }
+    /// Truthiness of a string
+    fn str(&self, value: &str) -> bool {
+        !value.is_empty()
+    }
+    /// Truthiness of a sequence, like `[T]`, given its length
+    fn seq(&self, len: usize) -> bool {
+        len != 0
+    }
+    /// Truthiness of a map, given its length
+    ///
+    /// Defaults to the same rule as [`Policy::seq`].
+    fn map(&self, len: usize) -> bool {
+        self.seq(len)
+    }
+}
+
+/// Python's rules, which are the same as the default rules.
+///
+/// This is also the Python-flavored mode for JSON. A `serde_json::Value` follows JavaScript's
+/// rules by default, but under `Python`, empty arrays and objects are falsy, like empty lists and
+/// dicts, and `NaN` is truthy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Python;
+
+impl Policy for Python {}
+
+/// JavaScript's rules: `NaN` is falsy, and sequences are always truthy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JavaScript;
+
+impl Policy for JavaScript {
+    fn float(&self, value: f64) -> bool {
+        !(value == 0.0 || value.is_nan())
+    }
+    fn seq(&self, _len: usize) -> bool {
+        true
+    }
+}
+
+/// Ruby's rules: only `None` (`nil`), `false`, and `()` are falsy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ruby;
+
+impl Policy for Ruby {
+    fn int(&self, _value: i128) -> bool {
+        true
+    }
+    fn float(&self, _value: f64) -> bool {
+        true
+    }
+    fn str(&self, _value: &str) -> bool {
+        true
+    }
+    fn seq(&self, _len: usize) -> bool {
+        true
+    }
+}
+
+/// Lua's rules, which are the same as Ruby's.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Lua;
+
+impl Policy for Lua {
+    fn int(&self, value: i128) -> bool {
+        Ruby.int(value)
+    }
+    fn float(&self, value: f64) -> bool {
+        Ruby.float(value)
+    }
+    fn str(&self, value: &str) -> bool {
+        Ruby.str(value)
+    }
+    fn seq(&self, len: usize) -> bool {
+        Ruby.seq(len)
+    }
+    fn map(&self, len: usize) -> bool {
+        Ruby.map(len)
+    }
+}
+
+/// Perl's rules: `"0"` is falsy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Perl;
+
+impl Policy for Perl {
+    fn str(&self, value: &str) -> bool {
+        !(value.is_empty() || value == "0")
+    }
+}
+
+/// PHP's rules: `"0"` and empty arrays are falsy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Php;
+
+impl Policy for Php {
+    fn str(&self, value: &str) -> bool {
+        Perl.str(value)
+    }
+}
+
+/// [JSONLogic](https://jsonlogic.com/truthy.html)'s rules: JavaScript's rules, except that
+/// empty sequences are falsy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JsonLogic;
+
+impl Policy for JsonLogic {
+    fn float(&self, value: f64) -> bool {
+        JavaScript.float(value)
+    }
+    fn map(&self, len: usize) -> bool {
+        JavaScript.map(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Truthy;
+
+    mod python {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(f64::NAN.truthy_with::<Python>());
+            assert!("0".truthy_under(&Python));
+            assert!((1, 2).truthy_with::<Python>());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(0u8.falsy_with::<Python>());
+            assert!(!"".truthy_under(&Python));
+            assert!(Vec::<u8>::new().falsy_with::<Python>());
+            assert!(().falsy_with::<Python>());
+        }
+    }
+    mod javascript {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!("0".truthy_under(&JavaScript));
+            assert!([0u8; 0].truthy_with::<JavaScript>());
+            assert!(Some(f64::INFINITY).truthy_with::<JavaScript>());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(f32::NAN.falsy_with::<JavaScript>());
+            assert!((-0.0f64).falsy_with::<JavaScript>());
+            assert!(!"".truthy_under(&JavaScript));
+            assert!(None::<u8>.falsy_with::<JavaScript>());
+        }
+    }
+    mod ruby {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(0i32.truthy_with::<Ruby>());
+            assert!(0.0f64.truthy_with::<Ruby>());
+            assert!("".truthy_under(&Ruby));
+            assert!(Some("").truthy_with::<Ruby>());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(false.falsy_with::<Ruby>());
+            assert!(None::<u8>.falsy_with::<Ruby>());
+            assert!(Some(false).falsy_with::<Ruby>());
+        }
+    }
+    mod lua {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(0u64.truthy_with::<Lua>());
+            assert!("".truthy_under(&Lua));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(false.falsy_with::<Lua>());
+            assert!(None::<&str>.falsy_with::<Lua>());
+        }
+    }
+    mod perl {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!("0.0".truthy_under(&Perl));
+            assert!("00".truthy_under(&Perl));
+            assert!(f64::NAN.truthy_with::<Perl>());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!"0".truthy_under(&Perl));
+            assert!(String::from("0").falsy_with::<Perl>());
+            assert!(0u8.falsy_with::<Perl>());
+            assert!(Vec::<u8>::new().falsy_with::<Perl>());
+        }
+    }
+    mod php {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!("0.0".truthy_under(&Php));
+            assert!(["0"].truthy_with::<Php>());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!"0".truthy_under(&Php));
+            assert!(Vec::<u8>::new().falsy_with::<Php>());
+            assert!((-0.0f32).falsy_with::<Php>());
+            let ok: Result<_, ()> = Ok("0");
+            assert!(ok.falsy_with::<Php>());
+        }
+    }
+    mod jsonlogic {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!("0".truthy_under(&JsonLogic));
+            assert!([0u8].truthy_with::<JsonLogic>());
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(f64::NAN.falsy_with::<JsonLogic>());
+            assert!([0u8; 0].falsy_with::<JsonLogic>());
+            assert!(!"".truthy_under(&JsonLogic));
+        }
+    }
+    mod custom {
+        use super::*;
+        use std::num::NonZeroI32;
+
+        /// Only positive numbers are truthy.
+        #[derive(Default)]
+        struct Positive;
+
+        impl Policy for Positive {
+            fn int(&self, value: i128) -> bool {
+                value > 0
+            }
+        }
+
+        #[test]
+        fn int() {
+            assert!(1i8.truthy_with::<Positive>());
+            assert!(u128::MAX.truthy_with::<Positive>());
+            assert!(NonZeroI32::new(-1).unwrap().falsy_with::<Positive>());
+            assert!((-1i64).falsy_with::<Positive>());
+            assert!(Some(0u8).falsy_with::<Positive>());
+        }
+    }
+}
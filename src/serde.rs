@@ -0,0 +1,507 @@
+//! Helpers for `serde`
+//!
+//! Requires the `serde` feature.
+//!
+//! ```
+//! # use serde_derive::Deserialize;
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(deserialize_with = "truthy::serde::deserialize_bool")]
+//!     verbose: bool,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{ "verbose": "yes" }"#).unwrap();
+//! assert!(config.verbose);
+//! ```
+//!
+//! [`is_truthy`] and [`is_falsy`] can be used with `skip_serializing_if`.
+//!
+//! ```
+//! # use serde_derive::Serialize;
+//! #[derive(Serialize)]
+//! struct Item {
+//!     #[serde(skip_serializing_if = "truthy::serde::is_falsy")]
+//!     name: &'static str,
+//!     #[serde(skip_serializing_if = "truthy::serde::is_falsy")]
+//!     count: u32,
+//! }
+//!
+//! let item = Item { name: "", count: 2 };
+//! assert_eq!(serde_json::to_string(&item).unwrap(), r#"{"count":2}"#);
+//! ```
+//!
+//! Modules like [`yes_no`] write a `bool` in another style, and can be used with
+//! `#[serde(with = "...")]`. They read values with [`deserialize_bool`], so anything that
+//! they write can be read back.
+//!
+//! ```
+//! # use serde_derive::{Deserialize, Serialize};
+//! #[derive(Deserialize, Serialize)]
+//! struct Settings {
+//!     #[serde(with = "truthy::serde::yes_no")]
+//!     color: bool,
+//!     #[serde(with = "truthy::serde::one_zero")]
+//!     cache: bool,
+//! }
+//!
+//! let settings = Settings { color: true, cache: false };
+//! let json = serde_json::to_string(&settings).unwrap();
+//! assert_eq!(json, r#"{"color":"yes","cache":0}"#);
+//!
+//! let settings: Settings = serde_json::from_str(&json).unwrap();
+//! assert!(settings.color);
+//! assert!(!settings.cache);
+//! ```
+use std::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+
+use super::parse::{parse_bool, parse_bool_lenient};
+use super::Truthy;
+
+/// Deserializes a `bool` from a boolean, number, string, or null.
+///
+/// Numbers and null use their `Truthy` implementations, and strings are parsed with
+/// [`parse_bool_lenient`].
+///
+/// ```
+/// # use serde_derive::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Flag(#[serde(deserialize_with = "truthy::serde::deserialize_bool")] bool);
+///
+/// let flags: Vec<Flag> = serde_json::from_str(r#"[true, 1, 0.5, "on", "x", 0, "0", "", null]"#).unwrap();
+/// let flags: Vec<bool> = flags.into_iter().map(|Flag(flag)| flag).collect();
+/// assert_eq!(flags, [true, true, true, true, true, false, false, false, false]);
+/// ```
+pub fn deserialize_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(LenientVisitor)
+}
+
+/// Deserializes a `bool`, rejecting ambiguous values.
+///
+/// Accepts booleans, the numbers `0` and `1` (including `0.0` and `1.0`), and strings accepted by
+/// [`parse_bool`]. Other numbers, other strings, and null are errors.
+///
+/// ```
+/// # use serde_derive::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Flag(#[serde(deserialize_with = "truthy::serde::deserialize_bool_strict")] bool);
+///
+/// assert!(serde_json::from_str::<Flag>(r#""off""#).is_ok());
+/// assert!(serde_json::from_str::<Flag>("2").is_err());
+/// assert!(serde_json::from_str::<Flag>("null").is_err());
+/// ```
+pub fn deserialize_bool_strict<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(StrictVisitor)
+}
+
+/// `true` if `value` is truthy.
+///
+/// For use with `#[serde(skip_serializing_if = "truthy::serde::is_truthy")]`.
+pub fn is_truthy<T: Truthy + ?Sized>(value: &T) -> bool {
+    value.truthy()
+}
+
+/// `true` if `value` is falsy.
+///
+/// For use with `#[serde(skip_serializing_if = "truthy::serde::is_falsy")]`.
+pub fn is_falsy<T: Truthy + ?Sized>(value: &T) -> bool {
+    value.falsy()
+}
+
+/// Deserializes an `Option<T>`, replacing falsy values with `None`.
+///
+/// ```
+/// # use serde_derive::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Retries {
+///     #[serde(default, deserialize_with = "truthy::serde::falsy_as_none")]
+///     max: Option<u32>,
+/// }
+///
+/// let retries: Retries = serde_json::from_str(r#"{ "max": 0 }"#).unwrap();
+/// assert_eq!(retries.max, None);
+/// ```
+pub fn falsy_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Truthy,
+{
+    Option::<T>::deserialize(deserializer).map(|value| value.filter(Truthy::truthy))
+}
+
+/// Creates a module for `#[serde(with = "...")]` that writes `true` and `false` as the given
+/// values.
+macro_rules! bool_style {
+    ($(#[$meta:meta])* $name:ident, $truthy:expr, $falsy:expr) => {
+        $(#[$meta])*
+        pub mod $name {
+            use ::serde::{Deserializer, Serialize, Serializer};
+
+            /// Serializes a `bool` in this module's style.
+            pub fn serialize<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+                if *value {
+                    $truthy.serialize(serializer)
+                } else {
+                    $falsy.serialize(serializer)
+                }
+            }
+
+            /// Deserializes with [`deserialize_bool`](super::deserialize_bool).
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+                super::deserialize_bool(deserializer)
+            }
+        }
+    };
+}
+
+bool_style! {
+    /// Writes a `bool` as `"yes"` or `"no"`
+    yes_no, "yes", "no"
+}
+bool_style! {
+    /// Writes a `bool` as `"on"` or `"off"`
+    on_off, "on", "off"
+}
+bool_style! {
+    /// Writes a `bool` as `"Y"` or `"N"`
+    y_n, "Y", "N"
+}
+bool_style! {
+    /// Writes a `bool` as `1` or `0`
+    one_zero, 1u8, 0u8
+}
+bool_style! {
+    /// Writes a `bool` as `"TRUE"` or `"FALSE"`
+    true_false_upper, "TRUE", "FALSE"
+}
+
+struct LenientVisitor;
+
+impl<'de> Visitor<'de> for LenientVisitor {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a boolean, number, string, or null")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<bool, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<bool, E> {
+        Ok(v.truthy())
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<bool, E> {
+        Ok(v.truthy())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<bool, E> {
+        Ok(v.truthy())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<bool, E> {
+        Ok(v.truthy())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<bool, E> {
+        Ok(v.truthy())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<bool, E> {
+        Ok(parse_bool_lenient(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<bool, E> {
+        Ok(false)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<bool, E> {
+        Ok(false)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserialize_bool(deserializer)
+    }
+}
+
+struct StrictVisitor;
+
+impl<'de> Visitor<'de> for StrictVisitor {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a boolean, 0 or 1, or a boolean word")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<bool, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<bool, E> {
+        match v {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<bool, E> {
+        match v {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Other(&format!("integer `{}`", v)), &self)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<bool, E> {
+        match v {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<bool, E> {
+        match v {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Other(&format!("integer `{}`", v)), &self)),
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<bool, E> {
+        if v == 0.0 {
+            Ok(false)
+        } else if v == 1.0 {
+            Ok(true)
+        } else {
+            Err(E::invalid_value(Unexpected::Float(v), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<bool, E> {
+        parse_bool(v).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserialize_bool_strict(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize)]
+    struct Lenient(#[serde(deserialize_with = "deserialize_bool")] bool);
+
+    #[derive(Debug, Deserialize)]
+    struct Strict(#[serde(deserialize_with = "deserialize_bool_strict")] bool);
+
+    fn lenient(json: &str) -> bool {
+        serde_json::from_str::<Lenient>(json).unwrap().0
+    }
+
+    fn strict(json: &str) -> Result<bool, serde_json::Error> {
+        serde_json::from_str::<Strict>(json).map(|Strict(value)| value)
+    }
+
+    mod lenient {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            for json in &["true", "1", "-1", "0.1", r#""yes""#, r#""On""#, r#""maybe""#] {
+                assert!(lenient(json), "{}", json);
+            }
+        }
+
+        #[test]
+        fn falsy() {
+            for json in &["false", "0", "0.0", r#""no""#, r#""0""#, r#""""#, "null"] {
+                assert!(!lenient(json), "{}", json);
+            }
+        }
+
+        #[test]
+        fn invalid() {
+            assert!(serde_json::from_str::<Lenient>("[]").is_err());
+        }
+    }
+    mod strict {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            for json in &["true", "1", "1.0", r#""yes""#, r#"" TRUE ""#] {
+                assert!(strict(json).unwrap(), "{}", json);
+            }
+        }
+
+        #[test]
+        fn falsy() {
+            for json in &["false", "0", "0.0", "-0.0", r#""off""#, r#""0""#] {
+                assert!(!strict(json).unwrap(), "{}", json);
+            }
+        }
+
+        #[test]
+        fn ambiguous() {
+            for json in &["2", "-1", "0.5", "1.1", r#""maybe""#, r#""""#, "null", "{}"] {
+                assert!(strict(json).is_err(), "{}", json);
+            }
+        }
+
+        #[test]
+        fn wide_integers() {
+            use serde::de::value::Error;
+            use serde::de::IntoDeserializer;
+
+            let strict = |value: i128| deserialize_bool_strict(value.into_deserializer());
+            assert_eq!(strict(1), Ok::<_, Error>(true));
+            assert_eq!(strict(0), Ok(false));
+            assert!(strict(i128::MIN).is_err());
+            let strict = |value: u128| deserialize_bool_strict(value.into_deserializer());
+            assert_eq!(strict(1), Ok::<_, Error>(true));
+            assert_eq!(strict(0), Ok(false));
+            let err = strict(u128::MAX).unwrap_err();
+            assert!(err.to_string().starts_with(&format!("invalid value: integer `{}`", u128::MAX)));
+        }
+
+        #[test]
+        fn error_message() {
+            let err = strict(r#""maybe""#).unwrap_err();
+            assert!(err
+                .to_string()
+                .starts_with(r#"invalid value: string "maybe", expected a boolean, 0 or 1, or a boolean word"#));
+        }
+    }
+    mod skip_serializing_if {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct SkipFalsy {
+            #[serde(skip_serializing_if = "is_falsy")]
+            name: &'static str,
+            #[serde(skip_serializing_if = "is_falsy")]
+            count: u32,
+            #[serde(skip_serializing_if = "is_falsy")]
+            tag: Option<u8>,
+        }
+
+        #[derive(Serialize)]
+        struct SkipTruthy {
+            #[serde(skip_serializing_if = "is_truthy")]
+            error: Option<&'static str>,
+        }
+
+        #[test]
+        fn falsy() {
+            let skipped = SkipFalsy { name: "", count: 0, tag: Some(0) };
+            let kept = SkipFalsy { name: "a", count: 1, tag: Some(1) };
+            assert_eq!(serde_json::to_string(&skipped).unwrap(), "{}");
+            assert_eq!(
+                serde_json::to_string(&kept).unwrap(),
+                r#"{"name":"a","count":1,"tag":1}"#,
+            );
+        }
+
+        #[test]
+        fn truthy() {
+            let skipped = SkipTruthy { error: Some("oops") };
+            let kept = SkipTruthy { error: None };
+            assert_eq!(serde_json::to_string(&skipped).unwrap(), "{}");
+            assert_eq!(serde_json::to_string(&kept).unwrap(), r#"{"error":null}"#);
+        }
+    }
+    mod falsy_as_none {
+        use super::*;
+
+        #[derive(Debug, Deserialize)]
+        struct Fields {
+            #[serde(default, deserialize_with = "falsy_as_none")]
+            count: Option<u32>,
+            #[serde(default, deserialize_with = "falsy_as_none")]
+            ratio: Option<f64>,
+        }
+
+        fn fields(json: &str) -> (Option<u32>, Option<f64>) {
+            let fields: Fields = serde_json::from_str(json).unwrap();
+            (fields.count, fields.ratio)
+        }
+
+        #[test]
+        fn truthy() {
+            assert_eq!(fields(r#"{"count":1,"ratio":0.5}"#), (Some(1), Some(0.5)));
+        }
+
+        #[test]
+        fn falsy() {
+            assert_eq!(fields(r#"{"count":0,"ratio":0.0}"#), (None, None));
+            assert_eq!(fields(r#"{"count":null}"#), (None, None));
+            assert_eq!(fields("{}"), (None, None));
+        }
+    }
+    mod styles {
+        use super::*;
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Styles {
+            #[serde(with = "yes_no")]
+            yes_no: bool,
+            #[serde(with = "on_off")]
+            on_off: bool,
+            #[serde(with = "y_n")]
+            y_n: bool,
+            #[serde(with = "one_zero")]
+            one_zero: bool,
+            #[serde(with = "true_false_upper")]
+            true_false_upper: bool,
+        }
+
+        const TRUTHY: Styles = Styles {
+            yes_no: true,
+            on_off: true,
+            y_n: true,
+            one_zero: true,
+            true_false_upper: true,
+        };
+
+        const FALSY: Styles = Styles {
+            yes_no: false,
+            on_off: false,
+            y_n: false,
+            one_zero: false,
+            true_false_upper: false,
+        };
+
+        #[test]
+        fn serialize() {
+            assert_eq!(
+                serde_json::to_string(&TRUTHY).unwrap(),
+                r#"{"yes_no":"yes","on_off":"on","y_n":"Y","one_zero":1,"true_false_upper":"TRUE"}"#,
+            );
+            assert_eq!(
+                serde_json::to_string(&FALSY).unwrap(),
+                r#"{"yes_no":"no","on_off":"off","y_n":"N","one_zero":0,"true_false_upper":"FALSE"}"#,
+            );
+        }
+
+        #[test]
+        fn round_trip() {
+            for styles in &[TRUTHY, FALSY] {
+                let json = serde_json::to_string(styles).unwrap();
+                assert_eq!(serde_json::from_str::<Styles>(&json).unwrap(), *styles);
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Implementations of `Truthy` for the string and path types in `std`
+//!
+//! Under a [`Policy`], strings that aren't UTF-8 are checked with [`Policy::str`] after invalid
+//! sequences are replaced with `U+FFFD`.
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::policy::Policy;
+use crate::Truthy;
+
+impl Truthy for String {
+    /// `true` if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// assert!(String::from(" ").truthy());
+    /// assert!(String::new().falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.str(self)
+    }
+}
+
+impl Truthy for OsStr {
+    /// `true` if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::ffi::OsStr;
+    /// assert!(OsStr::new("a").truthy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.str(&self.to_string_lossy())
+    }
+}
+
+impl Truthy for OsString {
+    /// `true` if not empty
+    fn truthy(&self) -> bool {
+        self.as_os_str().truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        self.as_os_str().truthy_under(policy)
+    }
+}
+
+impl Truthy for CStr {
+    /// `true` if not empty, not counting the nul terminator
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::ffi::CStr;
+    /// assert!(CStr::from_bytes_with_nul(b"\0").unwrap().falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        !self.to_bytes().is_empty()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.str(&self.to_string_lossy())
+    }
+}
+
+impl Truthy for CString {
+    /// `true` if not empty, not counting the nul terminator
+    fn truthy(&self) -> bool {
+        self.as_c_str().truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        self.as_c_str().truthy_under(policy)
+    }
+}
+
+impl Truthy for Path {
+    /// `true` if not empty
+    ///
+    /// ```
+    /// # use truthy::Truthy;
+    /// # use std::path::Path;
+    /// assert!(Path::new(".").truthy());
+    /// assert!(Path::new("").falsy());
+    /// ```
+    fn truthy(&self) -> bool {
+        self.as_os_str().truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        policy.str(&self.to_string_lossy())
+    }
+}
+
+impl Truthy for PathBuf {
+    /// `true` if not empty
+    fn truthy(&self) -> bool {
+        self.as_path().truthy()
+    }
+    fn truthy_under(&self, policy: &dyn Policy) -> bool {
+        self.as_path().truthy_under(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{Perl, Ruby};
+
+    fn is_truthy<T: Truthy>(value: T) -> bool {
+        value.truthy()
+    }
+
+    mod strings {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(String::from("0")));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(String::new()));
+        }
+    }
+    mod os_strings {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(OsString::from("a")));
+            assert!(is_truthy(OsStr::new("a")));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(OsString::new()));
+            assert!(!is_truthy(OsStr::new("")));
+        }
+    }
+    mod c_strings {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(CString::new("a").unwrap()));
+            assert!(is_truthy(CString::new(vec![0xff]).unwrap()));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(CString::default()));
+            assert!(!is_truthy(CString::default().as_c_str()));
+        }
+    }
+    mod paths {
+        use super::*;
+
+        #[test]
+        fn truthy() {
+            assert!(is_truthy(PathBuf::from("/")));
+            assert!(is_truthy(Path::new("a/b")));
+        }
+
+        #[test]
+        fn falsy() {
+            assert!(!is_truthy(PathBuf::new()));
+            assert!(!is_truthy(Path::new("")));
+        }
+    }
+
+    #[test]
+    fn policy() {
+        assert!(String::from("0").falsy_with::<Perl>());
+        assert!(OsString::from("0").falsy_with::<Perl>());
+        assert!(CString::new("0").unwrap().falsy_with::<Perl>());
+        assert!(PathBuf::from("0").falsy_with::<Perl>());
+        assert!(PathBuf::new().truthy_with::<Ruby>());
+        assert!(CString::new(vec![0xff]).unwrap().truthy_with::<Perl>());
+    }
+}
@@ -0,0 +1,195 @@
+//! Records of how a [`truthy_trace!`](crate::truthy_trace) expression was evaluated
+//!
+//! ```
+//! use truthy::truthy_trace;
+//!
+//! let (a, b, c) = (1, "", None::<u8>);
+//! let (result, trace) = truthy_trace!(a && (b || !c) && b);
+//! assert!(!result);
+//! assert_eq!(trace.to_string(), "\
+//! a && (b || !c) && b = false
+//!   a: truthy
+//!   b: falsy
+//!   c: falsy
+//!   b: falsy
+//! ");
+//! ```
+use std::fmt;
+
+/// The operands of an expression, in the order that they appear in its source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    expression: &'static str,
+    operands: Vec<Operand>,
+    result: bool,
+}
+
+impl Trace {
+    #[doc(hidden)]
+    pub fn new(expression: &'static str) -> Self {
+        Trace { expression, operands: Vec::new(), result: false }
+    }
+
+    #[doc(hidden)]
+    pub fn record(&mut self, source: &'static str, truthy: bool) -> bool {
+        self.operands.push(Operand { source, truthy: Some(truthy) });
+        truthy
+    }
+
+    #[doc(hidden)]
+    pub fn skip(&mut self, source: &'static str) {
+        self.operands.push(Operand { source, truthy: None });
+    }
+
+    #[doc(hidden)]
+    pub fn finish(mut self, result: bool) -> (bool, Self) {
+        self.result = result;
+        (result, self)
+    }
+
+    /// The source text of the whole expression
+    pub fn expression(&self) -> &'static str {
+        self.expression
+    }
+
+    /// Each operand, including the ones that were short-circuited
+    ///
+    /// `!` and parentheses aren't part of an operand, so `!(a || b)` has the operands `a` and
+    /// `b`.
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands
+    }
+
+    /// The value of the expression
+    pub fn result(&self) -> bool {
+        self.result
+    }
+
+    /// Emits the trace as a `DEBUG` event with the `expression`, `result`, and `operands`
+    /// fields.
+    #[cfg(feature = "tracing")]
+    pub fn emit(&self) {
+        let operands: Vec<String> = self.operands.iter().map(ToString::to_string).collect();
+        tracing::debug!(
+            expression = self.expression,
+            result = self.result,
+            operands = %operands.join(", "),
+            "evaluated truthy expression",
+        );
+    }
+}
+
+impl fmt::Display for Trace {
+    /// Writes the expression and its result, and then each operand on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} = {}", self.expression, self.result)?;
+        for operand in &self.operands {
+            writeln!(f, "  {}", operand)?;
+        }
+        Ok(())
+    }
+}
+
+/// An operand in a [`Trace`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Operand {
+    source: &'static str,
+    truthy: Option<bool>,
+}
+
+impl Operand {
+    /// The source text of the operand
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// The truthiness of the operand, or `None` if it wasn't evaluated
+    pub fn truthy(&self) -> Option<bool> {
+        self.truthy
+    }
+
+    /// Checks if the operand wasn't evaluated because of short-circuiting.
+    pub fn is_skipped(&self) -> bool {
+        self.truthy.is_none()
+    }
+}
+
+impl fmt::Display for Operand {
+    /// Writes the source and `truthy`, `falsy`, or `skipped`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.truthy {
+            Some(true) => "truthy",
+            Some(false) => "falsy",
+            None => "skipped",
+        };
+        write!(f, "{}: {}", self.source, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::truthy_trace;
+
+    #[test]
+    fn display() {
+        let (a, b) = (0u8, "b");
+        let (_, trace) = truthy_trace!(a || !(b && a) => b);
+        assert_eq!(trace.to_string(), "a || !(b && a) => b = true\n  a: falsy\n  b: truthy\n  a: falsy\n  b: truthy\n");
+        let (_, trace) = truthy_trace!(b || a);
+        assert_eq!(trace.to_string(), "b || a = true\n  b: truthy\n  a: skipped\n");
+    }
+
+    #[cfg(feature = "tracing")]
+    mod emit {
+        use std::fmt;
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        use crate::truthy_trace;
+
+        /// Collects the fields of each event
+        #[derive(Clone, Default)]
+        struct Collector(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Collector {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0.lock().unwrap().push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for Collector {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _span: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut self.clone());
+            }
+            fn enter(&self, _span: &Id) {}
+            fn exit(&self, _span: &Id) {}
+        }
+
+        #[test]
+        fn fields() {
+            let collector = Collector::default();
+            let (a, b) = (1u8, 0u8);
+            let (_, trace) = truthy_trace!(a && b);
+            tracing::subscriber::with_default(collector.clone(), || trace.emit());
+            let fields = collector.0.lock().unwrap();
+            let expected = [
+                "message=evaluated truthy expression",
+                r#"expression="a && b""#,
+                "result=false",
+                "operands=a: truthy, b: falsy",
+            ];
+            assert_eq!(*fields, expected);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! The official JSONLogic test suite, from <https://jsonlogic.com/tests.json>
+#![cfg(feature = "jsonlogic")]
+use serde_json::Value;
+use truthy::jsonlogic;
+
+#[test]
+fn official_suite() {
+    let suite: Vec<Value> = serde_json::from_str(include_str!("fixtures/jsonlogic.json")).unwrap();
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for case in &suite {
+        // Strings are comments that separate sections of the suite
+        let case = match case {
+            Value::Array(case) => case,
+            _ => continue,
+        };
+        count += 1;
+        let (rule, data, expected) = (&case[0], &case[1], &case[2]);
+        match jsonlogic::apply(rule, data) {
+            Ok(ref actual) if actual == expected => {}
+            actual => {
+                failures.push(format!("{} with {}: expected {}, got {:?}", rule, data, expected, actual))
+            }
+        }
+    }
+    assert_eq!(count, 275);
+    assert!(failures.is_empty(), "{} failures:\n{}", failures.len(), failures.join("\n"));
+}
@@ -0,0 +1,394 @@
+//! Derive macro for `truthy::Truthy`
+//!
+//! This crate is re-exported by `truthy` when its `derive` feature is enabled. You probably
+//! want to depend on `truthy` instead of using this crate directly.
+//!
+//! # Container attributes
+//! - `#[truthy(all)]` (default): truthy if every field is truthy
+//! - `#[truthy(any)]`: truthy if at least one field is truthy
+//! - `#[truthy(always)]`: always truthy, except for enum variants that are falsy because of
+//!   `zero_is_falsy` or a variant attribute
+//! - `#[truthy(zero_is_falsy)]`: enums only, the variant with discriminant `0` is falsy
+//!
+//! # Variant attributes
+//! - `#[truthy(true)]`: the variant is always truthy
+//! - `#[truthy(false)]`: the variant is always falsy
+//! - `#[truthy(all)]` or `#[truthy(any)]`: overrides the container's mode for the variant
+//!
+//! # Field attributes
+//! - `#[truthy(skip)]`: ignore the field
+//! - `#[truthy(with = path::to::fn)]`: use `fn(&FieldType) -> bool` instead of `Truthy`
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Expr, ExprLit, ExprUnary, Fields,
+    Ident, Lit, Path, Type, UnOp, Variant,
+};
+
+/// Derives `truthy::Truthy`.
+///
+/// See the crate-level documentation for the supported attributes.
+#[proc_macro_derive(Truthy, attributes(truthy))]
+pub fn derive_truthy(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// How the truthiness of each field is combined.
+#[derive(Clone, Copy)]
+enum Mode {
+    All,
+    Any,
+    Always,
+}
+
+/// Attributes on the struct or enum itself.
+struct Container {
+    mode: Option<Mode>,
+    zero_is_falsy: Option<Span>,
+}
+
+/// Attributes on an enum variant.
+enum VariantKind {
+    Fields(Option<Mode>),
+    Fixed(bool),
+}
+
+/// How a single field contributes to the truthiness of its container.
+enum FieldKind {
+    Use,
+    Skip,
+    With(Path),
+}
+
+/// Collects the field types that need a `Truthy` bound.
+struct Bounds {
+    params: Vec<Ident>,
+    bounded: Vec<Type>,
+}
+
+impl Bounds {
+    /// Marks `ty` as needing a bound if any type parameter appears in it.
+    fn add(&mut self, ty: &Type) {
+        let generic = self
+            .params
+            .iter()
+            .any(|param| contains_ident(ty.to_token_stream(), param));
+        let tokens = ty.to_token_stream().to_string();
+        let bounded = self
+            .bounded
+            .iter()
+            .any(|other| other.to_token_stream().to_string() == tokens);
+        if generic && !bounded {
+            self.bounded.push(ty.clone());
+        }
+    }
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let container = container_attrs(&input.attrs)?;
+    let mut bounds = Bounds {
+        params: input
+            .generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect(),
+        bounded: Vec::new(),
+    };
+    let truthy = body(&input.data, &container, &quote!(truthy()), &mut bounds)?;
+    let truthy_under = body(
+        &input.data,
+        &container,
+        &quote!(truthy_under(policy)),
+        &mut bounds,
+    )?;
+
+    let where_clause = input.generics.make_where_clause();
+    for ty in bounds.bounded {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::truthy::Truthy));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::truthy::Truthy for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn truthy(&self) -> bool {
+                #[allow(unused_imports)]
+                use ::truthy::Truthy as _;
+                #truthy
+            }
+
+            #[allow(unused_variables)]
+            fn truthy_under(&self, policy: &dyn ::truthy::policy::Policy) -> bool {
+                #[allow(unused_imports)]
+                use ::truthy::Truthy as _;
+                #truthy_under
+            }
+        }
+    })
+}
+
+/// The body of a `Truthy` method, where `call` is the method to call on each field.
+fn body(
+    data: &Data,
+    container: &Container,
+    call: &TokenStream2,
+    bounds: &mut Bounds,
+) -> syn::Result<TokenStream2> {
+    let mode = container.mode.unwrap_or(Mode::All);
+    let body = match data {
+        Data::Struct(data) => {
+            if let Some(span) = container.zero_is_falsy {
+                return Err(syn::Error::new(
+                    span,
+                    "`zero_is_falsy` is only supported on enums",
+                ));
+            }
+            let (pattern, truthiness) = if data.fields.is_empty() {
+                // Structs without fields are treated like `()`
+                let truthiness = match mode {
+                    Mode::Always => quote!(true),
+                    _ => quote!(false),
+                };
+                (fields_pattern(quote!(Self), &data.fields), truthiness)
+            } else {
+                fields_truthiness(quote!(Self), &data.fields, mode, call, bounds)?
+            };
+            quote! {
+                match self {
+                    #pattern => #truthiness,
+                }
+            }
+        }
+        Data::Enum(data) if data.variants.is_empty() => quote!(match *self {}),
+        Data::Enum(data) => {
+            let zeros = match container.zero_is_falsy {
+                Some(_) => zero_discriminants(data.variants.iter())?,
+                None => vec![false; data.variants.len()],
+            };
+            let mut arms = Vec::new();
+            for (variant, is_zero) in data.variants.iter().zip(zeros) {
+                let ident = &variant.ident;
+                let path = quote!(Self::#ident);
+                let (pattern, truthiness) = match variant_kind(&variant.attrs)? {
+                    VariantKind::Fixed(value) => {
+                        (fields_pattern(path, &variant.fields), quote!(#value))
+                    }
+                    _ if is_zero => (fields_pattern(path, &variant.fields), quote!(false)),
+                    // Unit variants hold a value, their discriminant, so they are truthy
+                    _ if variant.fields.is_empty() => {
+                        (fields_pattern(path, &variant.fields), quote!(true))
+                    }
+                    VariantKind::Fields(variant_mode) => fields_truthiness(
+                        path,
+                        &variant.fields,
+                        variant_mode.unwrap_or(mode),
+                        call,
+                        bounds,
+                    )?,
+                };
+                arms.push(quote!(#pattern => #truthiness,));
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`#[derive(Truthy)]` does not support unions",
+            ))
+        }
+    };
+    Ok(body)
+}
+
+/// Name of the binding for the field at `index` in a generated pattern.
+fn binding(index: usize) -> Ident {
+    format_ident!("__self_{}", index)
+}
+
+/// A pattern that binds every field of `path` by reference.
+fn fields_pattern(path: TokenStream2, fields: &Fields) -> TokenStream2 {
+    let bindings = (0..fields.len()).map(binding);
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => path,
+    }
+}
+
+/// A pattern for `fields`, and the truthiness of the bound fields according to `mode`.
+fn fields_truthiness(
+    path: TokenStream2,
+    fields: &Fields,
+    mode: Mode,
+    call: &TokenStream2,
+    bounds: &mut Bounds,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    if let Mode::Always = mode {
+        return Ok((fields_pattern(path, fields), quote!(true)));
+    }
+    let mut exprs = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let binding = binding(index);
+        match field_kind(&field.attrs)? {
+            FieldKind::Use => {
+                bounds.add(&field.ty);
+                exprs.push(quote!(#binding.#call));
+            }
+            FieldKind::With(path) => exprs.push(quote!(#path(#binding))),
+            FieldKind::Skip => {}
+        }
+    }
+    Ok((fields_pattern(path, fields), combine(mode, exprs)))
+}
+
+/// Joins the truthiness of each field according to `mode`.
+fn combine(mode: Mode, exprs: Vec<TokenStream2>) -> TokenStream2 {
+    match mode {
+        Mode::Always => quote!(true),
+        // Like a struct without fields, nothing to check is falsy
+        _ if exprs.is_empty() => quote!(false),
+        Mode::All => quote!(#(#exprs)&&*),
+        Mode::Any => quote!(#(#exprs)||*),
+    }
+}
+
+/// Finds which variants have a discriminant of `0`.
+///
+/// Explicit discriminants must be integer literals so that they can be evaluated here.
+fn zero_discriminants<'a>(variants: impl Iterator<Item = &'a Variant>) -> syn::Result<Vec<bool>> {
+    let mut next = 0i128;
+    let mut zeros = Vec::new();
+    for variant in variants {
+        let value = match &variant.discriminant {
+            Some((_, expr)) => discriminant_value(expr)?,
+            None => next,
+        };
+        zeros.push(value == 0);
+        next = value.wrapping_add(1);
+    }
+    Ok(zeros)
+}
+
+fn discriminant_value(expr: &Expr) -> syn::Result<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant_value(expr).map(|value| -value),
+        Expr::Group(group) => discriminant_value(&group.expr),
+        Expr::Paren(paren) => discriminant_value(&paren.expr),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "`zero_is_falsy` requires discriminants to be integer literals",
+        )),
+    }
+}
+
+fn contains_ident(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(i) => i == *ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn truthy_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("truthy"))
+}
+
+fn container_attrs(attrs: &[Attribute]) -> syn::Result<Container> {
+    let mut container = Container {
+        mode: None,
+        zero_is_falsy: None,
+    };
+    for attr in truthy_attrs(attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("zero_is_falsy") {
+                container.zero_is_falsy = Some(meta.path.span());
+                return Ok(());
+            }
+            let mode = if meta.path.is_ident("all") {
+                Mode::All
+            } else if meta.path.is_ident("any") {
+                Mode::Any
+            } else if meta.path.is_ident("always") {
+                Mode::Always
+            } else {
+                return Err(meta.error("expected `all`, `any`, `always`, or `zero_is_falsy`"));
+            };
+            if container.mode.replace(mode).is_some() {
+                return Err(meta.error("truthiness mode is already set"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(container)
+}
+
+fn variant_kind(attrs: &[Attribute]) -> syn::Result<VariantKind> {
+    let mut kind = None;
+    for attr in truthy_attrs(attrs) {
+        attr.parse_nested_meta(|meta| {
+            let new = if meta.path.is_ident("true") {
+                VariantKind::Fixed(true)
+            } else if meta.path.is_ident("false") {
+                VariantKind::Fixed(false)
+            } else if meta.path.is_ident("all") {
+                VariantKind::Fields(Some(Mode::All))
+            } else if meta.path.is_ident("any") {
+                VariantKind::Fields(Some(Mode::Any))
+            } else {
+                return Err(meta.error("expected `true`, `false`, `all`, or `any`"));
+            };
+            if kind.replace(new).is_some() {
+                return Err(meta.error("variant truthiness is already set"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(kind.unwrap_or(VariantKind::Fields(None)))
+}
+
+fn field_kind(attrs: &[Attribute]) -> syn::Result<FieldKind> {
+    let mut kind = FieldKind::Use;
+    for attr in truthy_attrs(attrs) {
+        attr.parse_nested_meta(|meta| {
+            let new = if meta.path.is_ident("skip") {
+                FieldKind::Skip
+            } else if meta.path.is_ident("with") {
+                FieldKind::With(meta.value()?.parse()?)
+            } else {
+                return Err(meta.error("expected `skip` or `with = ...`"));
+            };
+            if let FieldKind::Use = kind {
+                kind = new;
+                Ok(())
+            } else {
+                Err(meta.error("`skip` and `with` cannot be combined"))
+            }
+        })?;
+    }
+    Ok(kind)
+}
@@ -0,0 +1,202 @@
+use truthy::Truthy;
+
+#[derive(Truthy)]
+enum Mode {
+    #[truthy(false)]
+    Off,
+    Low,
+    High,
+}
+
+#[derive(Truthy)]
+#[truthy(zero_is_falsy)]
+enum Level {
+    Off,
+    Low,
+    High,
+}
+
+#[derive(Truthy)]
+#[truthy(zero_is_falsy)]
+enum Offset {
+    Down = -1,
+    None,
+    Up,
+}
+
+#[derive(Truthy)]
+#[truthy(zero_is_falsy)]
+enum Explicit {
+    Low = 1,
+    #[truthy(true)]
+    Zero = 0,
+    High = 2,
+}
+
+#[derive(Truthy)]
+enum Payload {
+    #[truthy(false)]
+    Empty,
+    Data(Vec<u8>),
+    Pair(u8, u8),
+    #[truthy(any)]
+    Either { left: u8, right: u8 },
+    Checked(#[truthy(skip)] u8, bool),
+}
+
+#[derive(Truthy)]
+#[truthy(any)]
+enum AnyPayload {
+    Pair(u8, u8),
+}
+
+#[derive(Truthy)]
+#[truthy(always)]
+#[allow(dead_code)]
+enum Always {
+    Unit,
+    Value(u8),
+}
+
+/// Variant attributes still apply under `always`.
+#[derive(Truthy)]
+#[truthy(always, zero_is_falsy)]
+enum AlwaysExcept {
+    Zero,
+    #[truthy(false)]
+    Off,
+    #[truthy(all)]
+    Data(u8),
+    Value(u8),
+}
+
+/// Like `Either<L, R>`, each variant delegates to its value.
+#[derive(Truthy)]
+enum Generic<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(Truthy)]
+enum Empty {}
+
+mod unit {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        assert!(Mode::Low.truthy());
+        assert!(Mode::High.truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        assert!(Mode::Off.falsy());
+    }
+}
+
+mod zero_is_falsy {
+    use super::*;
+
+    #[test]
+    fn implicit() {
+        assert!(Level::Off.falsy());
+        assert!(Level::Low.truthy());
+        assert!(Level::High.truthy());
+    }
+
+    #[test]
+    fn negative() {
+        assert!(Offset::Down.truthy());
+        assert!(Offset::None.falsy());
+        assert!(Offset::Up.truthy());
+    }
+
+    #[test]
+    fn overridden() {
+        assert!(Explicit::Low.truthy());
+        assert!(Explicit::Zero.truthy());
+        assert!(Explicit::High.truthy());
+    }
+}
+
+mod data {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        assert!(Payload::Data(vec![1]).truthy());
+        assert!(Payload::Pair(1, 1).truthy());
+        assert!(Payload::Either { left: 0, right: 1 }.truthy());
+        assert!(Payload::Checked(0, true).truthy());
+        assert!(AnyPayload::Pair(0, 1).truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        assert!(Payload::Empty.falsy());
+        assert!(Payload::Data(Vec::new()).falsy());
+        assert!(Payload::Pair(1, 0).falsy());
+        assert!(Payload::Either { left: 0, right: 0 }.falsy());
+        assert!(Payload::Checked(1, false).falsy());
+        assert!(AnyPayload::Pair(0, 0).falsy());
+    }
+
+    #[test]
+    fn always() {
+        assert!(Always::Value(0).truthy());
+        assert!(AlwaysExcept::Value(0).truthy());
+        assert!(AlwaysExcept::Data(1).truthy());
+    }
+
+    #[test]
+    fn always_overridden() {
+        assert!(AlwaysExcept::Zero.falsy());
+        assert!(AlwaysExcept::Off.falsy());
+        assert!(AlwaysExcept::Data(0).falsy());
+    }
+}
+
+mod generics {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        let left: Generic<_, ()> = Generic::Left(1u8);
+        let right: Generic<(), _> = Generic::Right("a");
+        assert!(left.truthy());
+        assert!(right.truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        let left: Generic<_, ()> = Generic::Left(0u8);
+        let right: Generic<u8, ()> = Generic::Right(());
+        assert!(left.falsy());
+        assert!(right.falsy());
+    }
+}
+
+#[test]
+fn empty() {
+    fn assert_truthy<T: Truthy>() {}
+    assert_truthy::<Empty>();
+}
+
+mod policy {
+    use super::*;
+    use truthy::policy::Perl;
+
+    #[test]
+    fn data() {
+        let left: Generic<_, ()> = Generic::Left("0");
+        assert!(left.truthy());
+        assert!(left.falsy_with::<Perl>());
+    }
+
+    #[test]
+    fn fixed() {
+        assert!(Mode::Off.falsy_with::<Perl>());
+        assert!(Mode::Low.truthy_with::<Perl>());
+    }
+}
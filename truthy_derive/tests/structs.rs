@@ -0,0 +1,213 @@
+use truthy::Truthy;
+
+#[derive(Truthy)]
+struct Named {
+    name: &'static str,
+    count: u32,
+}
+
+#[derive(Truthy)]
+#[truthy(any)]
+struct AnyNamed {
+    name: &'static str,
+    count: u32,
+}
+
+#[derive(Truthy)]
+struct Tuple(u8, Option<bool>);
+
+#[derive(Truthy)]
+struct Unit;
+
+#[derive(Truthy)]
+#[truthy(always)]
+struct AlwaysUnit;
+
+#[derive(Truthy)]
+#[truthy(always)]
+struct AlwaysNamed {
+    #[allow(dead_code)]
+    count: u32,
+}
+
+#[derive(Truthy)]
+struct Skipped {
+    name: &'static str,
+    #[truthy(skip)]
+    #[allow(dead_code)]
+    count: u32,
+}
+
+/// With every field skipped, there is nothing to check, like a struct without fields.
+#[derive(Truthy)]
+struct AllSkipped {
+    #[truthy(skip)]
+    #[allow(dead_code)]
+    count: u32,
+}
+
+fn is_large(value: &u32) -> bool {
+    *value > 100
+}
+
+#[derive(Truthy)]
+struct With {
+    #[truthy(with = is_large)]
+    count: u32,
+}
+
+/// `U` is skipped, so it doesn't need to implement `Truthy`.
+#[derive(Truthy)]
+struct Generic<T, U> {
+    value: Option<T>,
+    #[truthy(skip)]
+    #[allow(dead_code)]
+    other: U,
+}
+
+/// `Vec<T>` is truthy for any `T`, so `T` doesn't need to implement `Truthy`.
+#[derive(Truthy)]
+struct Items<T> {
+    items: Vec<T>,
+}
+
+struct NotTruthy;
+
+mod named {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        assert!(Named {
+            name: "a",
+            count: 1
+        }
+        .truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        assert!(Named { name: "", count: 1 }.falsy());
+        assert!(Named {
+            name: "a",
+            count: 0
+        }
+        .falsy());
+    }
+}
+
+mod any {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        assert!(AnyNamed { name: "", count: 1 }.truthy());
+        assert!(AnyNamed {
+            name: "a",
+            count: 0
+        }
+        .truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        assert!(AnyNamed { name: "", count: 0 }.falsy());
+    }
+}
+
+mod tuple {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        assert!(Tuple(1, Some(true)).truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        assert!(Tuple(1, None).falsy());
+        assert!(Tuple(0, Some(true)).falsy());
+    }
+}
+
+mod unit {
+    use super::*;
+
+    #[test]
+    fn falsy() {
+        assert!(Unit.falsy());
+    }
+
+    #[test]
+    fn always() {
+        assert!(AlwaysUnit.truthy());
+        assert!(AlwaysNamed { count: 0 }.truthy());
+    }
+}
+
+mod attributes {
+    use super::*;
+
+    #[test]
+    fn skip() {
+        assert!(Skipped {
+            name: "a",
+            count: 0
+        }
+        .truthy());
+        assert!(AllSkipped { count: 1 }.falsy());
+    }
+
+    #[test]
+    fn with() {
+        assert!(With { count: 101 }.truthy());
+        assert!(With { count: 100 }.falsy());
+    }
+}
+
+mod generics {
+    use super::*;
+
+    #[test]
+    fn truthy() {
+        assert!(Generic {
+            value: Some(1u8),
+            other: NotTruthy
+        }
+        .truthy());
+    }
+
+    #[test]
+    fn falsy() {
+        assert!(Generic {
+            value: Some(0u8),
+            other: NotTruthy
+        }
+        .falsy());
+    }
+
+    #[test]
+    fn field_bounds() {
+        assert!(Items {
+            items: vec![NotTruthy]
+        }
+        .truthy());
+        assert!(Items::<NotTruthy> { items: Vec::new() }.falsy());
+    }
+}
+
+mod policy {
+    use super::*;
+    use truthy::policy::{Perl, Ruby};
+
+    #[test]
+    fn fields() {
+        assert!(Named { name: "0", count: 1 }.falsy_with::<Perl>());
+        assert!(Named { name: "", count: 0 }.truthy_with::<Ruby>());
+    }
+
+    #[test]
+    fn with() {
+        assert!(With { count: 1 }.falsy_with::<Ruby>());
+    }
+}